@@ -1,16 +1,266 @@
 use codegen::{Field, Scope, Variant};
 use convert_case::{Case, Casing};
 use serde_json::{Map, Value};
-use std::fs::File;
+use std::collections::HashSet;
+use std::env;
+use std::fs::{self, File};
 use std::io::BufReader;
 use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Types signald sends to subscribed clients inside a ClientMessageWrapper. Only the
+/// ones the protocol document defines get a `ClientEvent` variant.
+const CLIENT_EVENTS: [&str; 4] = [
+    "IncomingMessage",
+    "ListenerState",
+    "WebSocketConnectionState",
+    "StorageChange",
+];
+
+/// Fields holding epoch milliseconds whose name doesn't say so
+const TIMESTAMP_FIELDS: [&str; 5] = ["added", "created", "date", "last_seen", "when"];
+
+/// (type, field) pairs holding epoch milliseconds under a name other types use for something else
+const TIMESTAMP_TYPE_FIELDS: [(&str, &str); 1] = [
+    // The timestamp of the quoted message, to match against the timestamps of messages
+    ("JsonQuote", "id"),
+];
+
+/// String fields whose allowed values are only listed in the protocol docs
+struct StringEnum {
+    name: &'static str,
+    doc: &'static str,
+    values: &'static [&'static str],
+    /// (type, field) pairs holding this enum
+    fields: &'static [(&'static str, &'static str)],
+}
+
+const STRING_ENUMS: [StringEnum; 13] = [
+    StringEnum {
+        name: "TrustLevel",
+        doc: "How much an identity key is trusted",
+        values: &["TRUSTED_UNVERIFIED", "TRUSTED_VERIFIED", "UNTRUSTED"],
+        fields: &[("TrustRequestV1", "trust_level"), ("IdentityKeyV1", "trust_level")],
+    },
+    StringEnum {
+        name: "ReceiptType",
+        doc: "Kind of receipt",
+        values: &["UNKNOWN", "DELIVERY", "READ", "VIEWED"],
+        fields: &[("ReceiptMessageV1", "type")],
+    },
+    StringEnum {
+        name: "AccessRequired",
+        doc: "Who is allowed to perform a group action",
+        values: &["UNKNOWN", "ANY", "MEMBER", "ADMINISTRATOR", "UNSATISFIABLE", "UNRECOGNIZED"],
+        fields: &[
+            ("GroupAccessControlV1", "link"),
+            ("GroupAccessControlV1", "attributes"),
+            ("GroupAccessControlV1", "members"),
+        ],
+    },
+    StringEnum {
+        name: "MemberRole",
+        doc: "Role of a group member",
+        values: &["UNKNOWN", "DEFAULT", "ADMINISTRATOR", "UNRECOGNIZED"],
+        fields: &[
+            ("GroupMemberV1", "role"),
+            ("GroupPendingMemberV1", "role"),
+            ("CreateGroupRequestV1", "member_role"),
+        ],
+    },
+    StringEnum {
+        name: "TypingAction",
+        doc: "Whether a contact started or stopped typing",
+        values: &["UNKNOWN", "STARTED", "STOPPED"],
+        fields: &[("TypingMessageV1", "action")],
+    },
+    StringEnum {
+        name: "OfferType",
+        doc: "Kind of call being offered",
+        values: &["AUDIO_CALL", "VIDEO_CALL"],
+        fields: &[("OfferMessageV1", "type")],
+    },
+    StringEnum {
+        name: "CallType",
+        doc: "Kind of call to offer",
+        values: &["audio_call", "video_call"],
+        fields: &[("SendCallOfferRequestV1", "call_type")],
+    },
+    StringEnum {
+        name: "HangupType",
+        doc: "Why a call was hung up",
+        values: &["normal", "accepted", "declined", "busy", "need_permission"],
+        fields: &[("HangupCallRequestV1", "type")],
+    },
+    StringEnum {
+        name: "WebSocketState",
+        doc: "State of the connection between signald and the Signal servers",
+        values: &[
+            "DISCONNECTED",
+            "CONNECTING",
+            "CONNECTED",
+            "RECONNECTING",
+            "DISCONNECTING",
+            "AUTHENTICATION_FAILED",
+            "FAILED",
+        ],
+        fields: &[("WebSocketConnectionStateV1", "state")],
+    },
+    StringEnum {
+        name: "WebSocketType",
+        doc: "Which of signald's connections to the Signal servers",
+        values: &["UNIDENTIFIED", "IDENTIFIED"],
+        fields: &[("WebSocketConnectionStateV1", "socket")],
+    },
+    StringEnum {
+        name: "AnnouncementsMode",
+        doc: "Whether only admins may send messages to a group",
+        values: &["UNKNOWN", "ENABLED", "DISABLED"],
+        fields: &[("JsonGroupV2InfoV1", "announcements")],
+    },
+    StringEnum {
+        name: "MessageRequestResponseType",
+        doc: "Response to a message request",
+        values: &["UNKNOWN", "ACCEPT", "DELETE", "BLOCK", "BLOCK_AND_DELETE", "UNBLOCK_AND_ACCEPT"],
+        fields: &[("JsonMessageRequestResponseMessageV1", "type")],
+    },
+    StringEnum {
+        name: "ContactDetailType",
+        doc: "Kind of address, email or phone number attached to a shared contact",
+        values: &["HOME", "WORK", "MOBILE", "CUSTOM"],
+        fields: &[
+            ("SharedContactEmailV1", "type"),
+            ("SharedContactPhoneV1", "type"),
+            ("SharedContactAddressV1", "type"),
+        ],
+    },
+];
+
+/// Request fields holding identifiers, and the validated type they are generated as
+const ID_FIELDS: [(&str, &str); 6] = [
+    ("account", "AccountId"),
+    ("username", "AccountId"),
+    ("group", "GroupId"),
+    ("groupID", "GroupId"),
+    ("group_id", "GroupId"),
+    ("recipientGroupId", "GroupId"),
+];
+
+/// Actions that block until the user does something, which the default timeout doesn't apply to
+const BLOCKING_ACTIONS: [&str; 2] = ["wait_for_scan", "finish_link"];
+
+const BLOCKING_TIMEOUT: &str = "let timeout = match timeout { Timeout::Default => Timeout::Never, timeout => timeout };";
+
+/// Environment variable pointing the build at a different protocol document
+const PROTOCOL_ENV: &str = "SIGNALD_PROTOCOL";
+
+/// Types and fields the hand-written modules use, which every protocol document has to define
+const REQUIRED_TYPES: [(&str, &str, &[&str]); 20] = [
+    ("v1", "JsonAddress", &["number", "uuid", "relay"]),
+    ("v0", "JsonAddress", &["number", "uuid", "relay"]),
+    ("v1", "SubscribeRequest", &["account"]),
+    ("v1", "UnsubscribeRequest", &["account"]),
+    ("v1", "ResolveAddressRequest", &["account", "partial"]),
+    ("v1", "VersionRequest", &[]),
+    ("v1", "JsonVersionMessage", &["version"]),
+    (
+        "v1",
+        "IncomingMessage",
+        &[
+            "account",
+            "source",
+            "source_device",
+            "timestamp",
+            "server_receiver_timestamp",
+            "data_message",
+            "sync_message",
+            "call_message",
+            "receipt_message",
+            "typing_message",
+        ],
+    ),
+    (
+        "v1",
+        "JsonDataMessage",
+        &[
+            "timestamp",
+            "attachments",
+            "body",
+            "group",
+            "groupV2",
+            "expiresInSeconds",
+            "quote",
+            "reaction",
+            "remoteDelete",
+            "mentions",
+            "payment",
+            "is_expiration_update",
+        ],
+    ),
+    ("v1", "JsonReaction", &["emoji", "remove", "targetAuthor", "targetSentTimestamp"]),
+    ("v1", "RemoteDelete", &["target_sent_timestamp"]),
+    ("v1", "ReceiptMessage", &["type", "timestamps"]),
+    ("v1", "TypingMessage", &["action", "group_id"]),
+    ("v1", "JsonSyncMessage", &["sent"]),
+    ("v1", "JsonSentTranscriptMessage", &["destination", "timestamp", "message"]),
+    ("v1", "JsonGroupInfo", &["groupId"]),
+    ("v1", "JsonGroupV2Info", &["id"]),
+    ("v1", "CallMessage", &[]),
+    ("v1", "JsonAttachment", &[]),
+    ("v1", "Payment", &[]),
+];
+
+/// Actions the hand-written modules call, with their request types
+const REQUIRED_ACTIONS: [(&str, &str); 4] = [
+    ("subscribe", "SubscribeRequest"),
+    ("unsubscribe", "UnsubscribeRequest"),
+    ("resolve_address", "ResolveAddressRequest"),
+    ("version", "VersionRequest"),
+];
 
 fn main() {
-    let file = File::open("protocol.json").expect("Can't find protocol document");
+    println!("cargo:rerun-if-env-changed={}", PROTOCOL_ENV);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // Relative paths would resolve against this crate rather than the crate being built
+    let protocol_path = match env::var_os(PROTOCOL_ENV) {
+        Some(path) => {
+            let path = PathBuf::from(path);
+            if !path.is_absolute() {
+                panic!("{} must be an absolute path, got {}", PROTOCOL_ENV, path.display());
+            }
+            path
+        }
+        None => PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set")).join("protocol.json"),
+    };
+    println!("cargo:rerun-if-changed={}", protocol_path.display());
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set"));
+
+    let file = File::open(&protocol_path)
+        .unwrap_or_else(|e| panic!("Can't open protocol document {}: {}", protocol_path.display(), e));
+    // Kept so the compiled protocol can be compared with the daemon's at runtime
+    fs::copy(&protocol_path, out_dir.join("protocol.json")).expect("Failed to copy protocol document");
 
     let buf = BufReader::new(file);
 
     let protocol: Value = serde_json::from_reader(buf).expect("Can't parse protocol document");
+    check_required(&protocol, &protocol_path);
+
+    // Releases before stories have no story_message, so `Event::Story` only exists with them
+    println!("cargo:rustc-check-cfg=cfg(signald_stories)");
+    if protocol["types"]["v1"]["IncomingMessage"]["fields"].get("story_message").is_some() {
+        println!("cargo:rustc-cfg=signald_stories");
+    }
+
+    // Request types get a builder taking their required fields
+    let requests: HashSet<String> = protocol["actions"]["v1"]
+        .as_object()
+        .unwrap()
+        .values()
+        .filter_map(|action| action["request"].as_str())
+        .map(str::to_owned)
+        .collect();
 
     {
         let types = protocol["types"].as_object().unwrap();
@@ -20,12 +270,28 @@ fn main() {
         types_decl.import("serde", "Deserialize");
         types_decl.import("std::collections", "HashMap");
         types_decl.import("crate::errors", "SignaldError");
+        types_decl.import("serde_json", "Value");
+        types_decl.import("crate::socket", "ConnectionState");
+        types_decl.import("crate::timestamp", "Timestamp");
+        types_decl.import("crate::ids", "{AccountId, GroupId}");
+        types_decl.import("uuid", "Uuid");
 
         let mut variants: Vec<Variant> = Vec::new();
-        for version in vec!["v1", "v0"].iter() {
+        let mut deprecated = Vec::new();
+        for version in ["v1", "v0"].iter() {
             let types = types[*version].as_object().unwrap();
-            add_types(&mut types_decl, types, version, &mut variants);
+            add_types(&mut types_decl, types, version, &mut variants, &requests);
+
+            for (key, value) in types.iter() {
+                if let Some(note) = get_deprecation(value) {
+                    deprecated.push((key.to_owned() + &version.to_uppercase(), note));
+                }
+            }
         }
+
+        add_string_enums(&mut types_decl);
+        add_error_kinds(&mut types_decl, types["v1"].as_object().unwrap(), "v1");
+        add_client_events(&mut types_decl, types["v1"].as_object().unwrap(), "v1");
         let types_enum = types_decl
             .new_enum("SignaldTypes")
             .vis("pub")
@@ -39,10 +305,32 @@ fn main() {
             types_enum.push_variant(variant);
         }
 
-        let mut source_file = File::create("src/types.rs").expect("Can't create source file");
+        let mut tests_decl = Scope::new();
+        tests_decl.import("crate::types", "*");
+        tests_decl.import("serde_json", "Value");
+        for version in ["v1", "v0"].iter() {
+            add_example_tests(&mut tests_decl, types[*version].as_object().unwrap(), version, &requests);
+        }
+
+        let mut source_file = File::create(out_dir.join("example_tests.rs")).expect("Can't create source file");
 
         source_file
-            .write_all(types_decl.to_string().as_bytes())
+            .write_all(tests_decl.to_string().as_bytes())
+            .expect("Failed to write to source file");
+
+        // codegen can't put attributes on structs, so mark deprecated ones in the output
+        let mut source = types_decl.to_string();
+        for (type_name, note) in deprecated {
+            for end in [" {", ";"].iter() {
+                let decl = format!("\npub struct {}{}", type_name, end);
+                source = source.replace(&decl, &format!("\n#[deprecated(note = \"{}\")]{}", note, decl));
+            }
+        }
+
+        let mut source_file = File::create(out_dir.join("types.rs")).expect("Can't create source file");
+
+        source_file
+            .write_all(source.as_bytes())
             .expect("Failed to write to source file");
     }
 
@@ -55,18 +343,26 @@ fn main() {
         actions_decl.import("crate", "SocketError");
         actions_decl.import("crate::types", "*");
         actions_decl.import("uuid", "Uuid");
+        actions_decl.import("crate::socket", "Timeout");
         actions_decl.import("crate::socket", "MessageCommon");
         actions_decl.import("crate::errors", "SignaldError");
+        actions_decl.import("serde_json", "Value");
+        actions_decl.import("crate::account", "AccountHandle");
+        actions_decl.import("crate::ids", "GroupId");
+        actions_decl.import("crate::timestamp", "Timestamp");
 
-        add_actions(&mut actions_decl, actions, "v1");
+        add_actions(&mut actions_decl, actions, protocol["types"]["v1"].as_object().unwrap(), "v1");
+        add_action_registry(&mut actions_decl, actions, "v1");
+        add_account_handle(&mut actions_decl, actions, protocol["types"]["v1"].as_object().unwrap(), "v1");
 
         actions_decl
             .new_struct("SocketWrapper")
             .vis("pub")
             .generic("T")
+            .derive("Clone")
             .field("pub socket", "T");
 
-        let mut source_file = File::create("src/actions.rs").expect("Can't create source file");
+        let mut source_file = File::create(out_dir.join("actions.rs")).expect("Can't create source file");
 
         source_file
             .write_all(actions_decl.to_string().as_bytes())
@@ -74,7 +370,37 @@ fn main() {
     }
 }
 
-fn add_actions(scope: &mut Scope, actions: &Map<String, Value>, version: &str) {
+/// Fail the build with every required type, field and action the protocol document lacks
+fn check_required(protocol: &Value, path: &Path) {
+    let mut missing = Vec::new();
+    for (version, type_name, fields) in REQUIRED_TYPES.iter() {
+        let info = &protocol["types"][*version][*type_name];
+        if info.is_null() {
+            missing.push(format!("type {} ({})", type_name, version));
+            continue;
+        }
+        for field in fields.iter() {
+            if info["fields"].get(*field).is_none() {
+                missing.push(format!("field {}.{} ({})", type_name, field, version));
+            }
+        }
+    }
+    for (action, request) in REQUIRED_ACTIONS.iter() {
+        if protocol["actions"]["v1"][*action]["request"] != *request {
+            missing.push(format!("action {} taking {} (v1)", action, request));
+        }
+    }
+
+    if !missing.is_empty() {
+        panic!(
+            "Protocol document {} lacks what signald-rs needs: {}",
+            path.display(),
+            missing.join(", ")
+        );
+    }
+}
+
+fn add_actions(scope: &mut Scope, actions: &Map<String, Value>, types: &Map<String, Value>, version: &str) {
     let version = version.to_uppercase();
     let api_impl = scope
         .new_impl("SocketWrapper")
@@ -83,6 +409,7 @@ fn add_actions(scope: &mut Scope, actions: &Map<String, Value>, version: &str) {
         .bound("T", "AsyncSocket");
 
     let mut lines = Vec::new();
+    let mut error_enums = Vec::new();
 
     for (key, value) in actions.iter() {
         let request_type = value["request"].as_str().unwrap().to_owned() + &version;
@@ -99,11 +426,12 @@ fn add_actions(scope: &mut Scope, actions: &Map<String, Value>, version: &str) {
             "        if let SignaldTypes::{}(msg) = msg {{",
             request_type
         ));
-        lines.push(format!("            self.{}(msg, Some(id)).await", key));
+        lines.push(format!("            self.{}(msg, Some(id), timeout).await", key));
+        lines.push("                .map_err(SocketError::from)".to_owned());
 
         if let Some(response) = &response_type {
             lines.push(format!(
-                "                .map(|response| SignaldTypes::{}(response))",
+                "                .map(SignaldTypes::{})",
                 response
             ));
         } else {
@@ -115,25 +443,40 @@ fn add_actions(scope: &mut Scope, actions: &Map<String, Value>, version: &str) {
         lines.push("        }".to_owned());
         lines.push("    },".to_owned());
 
+        let error_type = key.to_case(Case::Pascal) + "Error";
+        error_enums.push((key.to_owned(), error_type.clone(), value));
+
         let new_fn = api_impl.new_fn(key.as_str());
 
         if let Some(doc) = value.get("doc") {
             new_fn.doc(doc.as_str().unwrap());
         }
 
+        if let Some(note) = get_deprecation(value) {
+            new_fn
+                .attr(&format!("deprecated(note = \"{}\")", note))
+                .line(format!("crate::socket::report_deprecated(\"{}\", {});", key, get_removal_date(value)));
+        }
+
         new_fn
             .vis("pub")
             .set_async(true)
             .ret(format!(
-                    "Result<{}, SocketError>",
+                    "Result<{}, {}>",
                     match &response_type {
                         Some(response_type) => response_type.clone(),
                         None => String::from("()")
-                    }
+                    },
+                    error_type
             ))
-            .arg_mut_self()
+            .arg_ref_self()
             .arg("msg", &request_type)
             .arg("id", "Option<Uuid>")
+            .arg("timeout", "Timeout");
+        if BLOCKING_ACTIONS.contains(&key.as_str()) {
+            new_fn.line(BLOCKING_TIMEOUT);
+        }
+        new_fn
             .line("let id = match id {")
             .line("    Some(id) => id,")
             .line("    None => Uuid::new_v4()")
@@ -145,22 +488,21 @@ fn add_actions(scope: &mut Scope, actions: &Map<String, Value>, version: &str) {
             .line("    msg")
             .line(");")
             .line("")
-            .line("let mut msg = serde_json::to_vec(&msg).unwrap();")
+            .line("let mut msg = serde_json::to_vec(&msg)?;")
             .line("msg.push(b'\\n');")
             .line("")
-            .line("self.socket.write(&msg, &id).await?;")
-            .line("let response = self.socket.get_response(id).await?.get(\"data\").unwrap().clone();")
+            .line("let response = self.socket.request(&msg, id, timeout).await?;")
             .line("")
             .line("match response.get(\"error\") {")
             .line(
                 match response_type {
                     Some(response_type) => {
-                        format!("    None => Ok(serde_json::from_value::<{}>(response).unwrap()),", response_type)
+                        format!("    None => Ok(serde_json::from_value::<{}>(response[\"data\"].clone())?),", response_type)
                     },
                     None => String::from("    None => Ok(()),")
                 }
             )
-            .line("    Some(_) => Err(SocketError::Signald(serde_json::from_value::<SignaldError>(response).unwrap()))")
+            .line("    Some(_) => Err(SignaldErrorKind::from(serde_json::from_value::<SignaldError>(response)?).into())")
             .line("}");
     }
 
@@ -168,12 +510,14 @@ fn add_actions(scope: &mut Scope, actions: &Map<String, Value>, version: &str) {
         .new_fn("remote_call")
         .set_async(true)
         .vis("pub")
-        .arg_mut_self()
+        .arg_ref_self()
         .arg("api_fn", "&str")
         .arg("id", "Uuid")
+        .arg("timeout", "Timeout")
         .arg("msg", "SignaldTypes")
         .ret("Result<SignaldTypes, SocketError>")
         .doc("Call api function indirectly from string key")
+        .allow("deprecated")
         .line("match api_fn {");
 
     for line in lines {
@@ -181,6 +525,310 @@ fn add_actions(scope: &mut Scope, actions: &Map<String, Value>, version: &str) {
     }
     call_fn.line("    _ => Err(SocketError::General(\"Unknown api function\"))");
     call_fn.line("}");
+
+    let json_fn = api_impl
+        .new_fn("call_json")
+        .set_async(true)
+        .vis("pub")
+        .arg_ref_self()
+        .arg("action", "&str")
+        .arg("version", "&str")
+        .arg("payload", "Value")
+        .arg("id", "Option<Uuid>")
+        .arg("timeout", "Timeout")
+        .ret("Result<Value, SocketError>")
+        .doc("Call an action with a JSON payload, which is checked against the action's request type, nested types included, before it is sent.\nReturns the `data` of the response as signald sent it.")
+        .allow("deprecated")
+        .line(format!("if version != \"{}\" {{", version.to_lowercase()))
+        .line("    return Err(SocketError::General(\"Unsupported action version\"));")
+        .line("}")
+        .line("")
+        .line("match action {");
+
+    for (key, value) in actions.iter() {
+        let request = value["request"].as_str().unwrap();
+
+        json_fn
+            .line(format!("    \"{}\" => {{", key))
+            .line(format!("        crate::socket::check_fields(&payload, \"{}\", FIELDS)?;", request))
+            .line(format!("        let msg: {}{} = serde_json::from_value(payload)?;", request, version));
+        if BLOCKING_ACTIONS.contains(&key.as_str()) {
+            json_fn.line(format!("        {}", BLOCKING_TIMEOUT));
+        }
+        if get_deprecation(value).is_some() {
+            json_fn.line(format!(
+                "        crate::socket::report_deprecated(\"{}\", {});",
+                key,
+                get_removal_date(value)
+            ));
+        }
+        json_fn
+            .line(format!("        crate::socket::request_data(&self.socket, \"{}\", msg, id, timeout).await", key))
+            .line("    },");
+    }
+    json_fn
+        .line("    _ => Err(SocketError::General(\"Unknown api function\")),")
+        .line("}");
+
+    for (key, error_type, value) in error_enums {
+        add_action_error(scope, &key, &error_type, value, &version);
+    }
+
+    add_field_table(scope, types, &version);
+}
+
+/// Generate `FIELDS`, the field names of every type along with the type of the fields
+/// holding one, for `call_json` to check payloads with
+fn add_field_table(scope: &mut Scope, types: &Map<String, Value>, version: &str) {
+    let mut table = vec![String::from("const FIELDS: &crate::socket::FieldTable = &[")];
+    for (key, value) in types.iter() {
+        let fields: Vec<String> = value["fields"]
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(field, info)| {
+                let nested = match info["version"].as_str() {
+                    Some(nested) if nested.to_uppercase() == version && types.contains_key(info["type"].as_str().unwrap()) => {
+                        info["type"].as_str()
+                    }
+                    _ => None,
+                };
+                format!("({:?}, {:?})", field, nested)
+            })
+            .collect();
+        table.push(format!("    ({:?}, &[{}]),", key, fields.join(", ")));
+    }
+    table.push("];".to_owned());
+    scope.raw(&table.join("\n"));
+}
+
+/// Generate `ACTIONS`, describing every action for tools that enumerate them at runtime
+fn add_action_registry(scope: &mut Scope, actions: &Map<String, Value>, version: &str) {
+    scope
+        .new_struct("ActionInfo")
+        .vis("pub")
+        .derive("Clone")
+        .derive("Copy")
+        .derive("Debug")
+        .doc("Description of an action this crate has bindings for")
+        .field("pub name", "&'static str")
+        .field("pub version", "&'static str")
+        .field("pub request", "&'static str")
+        .field("pub response", "Option<&'static str>")
+        .field("pub doc", "Option<&'static str>")
+        .field("pub errors", "&'static [&'static str]")
+        .field("pub deprecated", "bool");
+
+    let mut registry = vec![
+        String::from("/// Every action this crate has bindings for, sorted by name"),
+        String::from("pub const ACTIONS: &[ActionInfo] = &["),
+    ];
+    for (key, value) in actions.iter() {
+        let type_name = |name: &str| match name {
+            "String" => name.to_owned(),
+            _ => name.to_owned() + &version.to_uppercase(),
+        };
+        let errors: Vec<String> = value["errors"]
+            .as_array()
+            .map(|errors| errors.iter().filter_map(|error| error["name"].as_str()).collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|error| format!("{:?}", error))
+            .collect();
+
+        registry.push("    ActionInfo {".to_owned());
+        registry.push(format!("        name: {:?},", key));
+        registry.push(format!("        version: {:?},", version));
+        registry.push(format!("        request: {:?},", type_name(value["request"].as_str().unwrap())));
+        registry.push(format!("        response: {:?},", value["response"].as_str().map(type_name)));
+        registry.push(format!("        doc: {:?},", value["doc"].as_str()));
+        registry.push(format!("        errors: &[{}],", errors.join(", ")));
+        registry.push(format!("        deprecated: {},", get_deprecation(value).is_some()));
+        registry.push("    },".to_owned());
+    }
+    registry.push("];".to_owned());
+    scope.raw(&registry.join("\n"));
+
+    scope
+        .new_fn("action_info")
+        .vis("pub")
+        .arg("name", "&str")
+        .ret("Option<&'static ActionInfo>")
+        .doc("Look up an action in `ACTIONS` by name")
+        .line("ACTIONS.iter().find(|action| action.name == name)");
+}
+
+/// Fields of a request naming the account it acts as, preferring `account`
+const ACCOUNT_FIELDS: [&str; 2] = ["account", "username"];
+
+/// Generate the `AccountHandle` version of every action acting as an account, which
+/// checks the request is for the handle's account before passing it on to `SocketWrapper`,
+/// and a builder for the action's request with the account already filled in
+fn add_account_handle(scope: &mut Scope, actions: &Map<String, Value>, types: &Map<String, Value>, version: &str) {
+    let version = version.to_uppercase();
+    let handle_impl = scope
+        .new_impl("AccountHandle")
+        .generic("T")
+        .target_generic("T")
+        .bound("T", "AsyncSocket");
+
+    for (key, value) in actions.iter() {
+        let request = value["request"].as_str().unwrap();
+        let fields = types[request]["fields"].as_object().unwrap();
+        let account_fields: Vec<(&str, bool)> = ACCOUNT_FIELDS
+            .iter()
+            .filter(|field| fields.contains_key(**field))
+            .map(|field| (*field, fields[*field]["required"].as_bool().unwrap_or(false)))
+            .collect();
+        if account_fields.is_empty() {
+            continue;
+        }
+
+        let response_type = match value["response"].as_str() {
+            Some("String") => String::from("String"),
+            Some(response) => response.to_owned() + &version,
+            None => String::from("()"),
+        };
+        let request_type = request.to_owned() + &version;
+
+        let builder_fn = handle_impl
+            .new_fn(&format!("{}_request", key))
+            .vis("pub")
+            .arg_ref_self()
+            .ret(format!("{}Builder", request_type))
+            .doc(&format!(
+                "Start building the request of [`AccountHandle::{}`], with the handle's account filled in",
+                key
+            ));
+        let mut args = Vec::new();
+        for (field, info) in fields.iter().filter(|(_, info)| info["required"].as_bool().unwrap_or(false)) {
+            let name = get_field_name(field);
+            if ACCOUNT_FIELDS.contains(&field.as_str()) {
+                args.push(String::from("self.id().clone()"));
+            } else {
+                builder_fn.arg(&name, format!("impl Into<{}>", get_field_type(&request_type, field, info, true)));
+                args.push(name);
+            }
+        }
+        builder_fn.line(format!("{}::builder({})", request_type, args.join(", ")));
+        if let Some((field, false)) = account_fields.first() {
+            builder_fn.line(format!("    .{}(self.id().clone())", get_field_name(field)));
+        }
+
+        let new_fn = handle_impl.new_fn(key.as_str());
+        if let Some(doc) = value.get("doc") {
+            new_fn.doc(doc.as_str().unwrap());
+        }
+        if let Some(note) = get_deprecation(value) {
+            new_fn.attr(&format!("deprecated(note = \"{}\")", note));
+        }
+
+        new_fn
+            .vis("pub")
+            .set_async(true)
+            .allow("deprecated")
+            .ret(format!("Result<{}, {}>", response_type, key.to_case(Case::Pascal) + "Error"))
+            .arg_ref_self()
+            .arg(if account_fields[0].1 { "msg" } else { "mut msg" }, &request_type)
+            .arg("id", "Option<Uuid>")
+            .arg("timeout", "Timeout");
+
+        for (i, (field, required)) in account_fields.into_iter().enumerate() {
+            let field = get_field_name(field);
+            new_fn.line(match (i, required) {
+                (_, true) => format!("self.check(&msg.{})?;", field),
+                (0, false) => format!("self.claim_optional(&mut msg.{})?;", field),
+                _ => format!("self.check_optional(&msg.{})?;", field),
+            });
+        }
+        new_fn.line(format!("self.signald.{}(msg, id, timeout).await", key));
+    }
+}
+
+/// Generate the error enum for a single action from the errors protocol.json lists for it
+fn add_action_error(scope: &mut Scope, action: &str, error_type: &str, value: &Value, version: &str) {
+    let errors: Vec<(String, String)> = value["errors"]
+        .as_array()
+        .map(|errors| errors.iter().filter_map(|error| error["name"].as_str()).collect::<Vec<_>>())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|key| {
+            let variant = key.strip_suffix("Error").unwrap_or(key).to_owned();
+            (key.to_owned(), variant)
+        })
+        .collect();
+
+    let error_enum = scope
+        .new_enum(error_type)
+        .vis("pub")
+        .derive("Debug")
+        .doc(&format!("Everything the {} action can fail with", action));
+
+    for (key, variant) in errors.iter() {
+        error_enum.push_variant(Variant::new(
+            format!("{}({}{})", variant, key, version).as_str(),
+        ));
+    }
+    error_enum.push_variant(Variant::new("Unexpected(SignaldErrorKind)"));
+    error_enum.push_variant(Variant::new("Socket(SocketError)"));
+
+    let from_kind = scope
+        .new_impl(error_type)
+        .impl_trait("From<SignaldErrorKind>")
+        .new_fn("from")
+        .arg("error", "SignaldErrorKind")
+        .ret("Self");
+    if errors.is_empty() {
+        from_kind.line(format!("{}::Unexpected(error)", error_type));
+    } else {
+        from_kind.line("match error {");
+        for (_, variant) in errors.iter() {
+            from_kind.line(format!(
+                "    SignaldErrorKind::{}(error) => {}::{}(error),",
+                variant, error_type, variant
+            ));
+        }
+        from_kind
+            .line(format!("    error => {}::Unexpected(error),", error_type))
+            .line("}");
+    }
+
+    scope
+        .new_impl(error_type)
+        .impl_trait("From<SocketError>")
+        .new_fn("from")
+        .arg("error", "SocketError")
+        .ret("Self")
+        .line("match error {")
+        .line("    SocketError::Signald(error) => error.into(),")
+        .line(format!("    error => {}::Socket(error),", error_type))
+        .line("}");
+
+    scope
+        .new_impl(error_type)
+        .impl_trait("From<serde_json::Error>")
+        .new_fn("from")
+        .arg("error", "serde_json::Error")
+        .ret("Self")
+        .line(format!("{}::Socket(error.into())", error_type));
+
+    let into_socket = scope
+        .new_impl("SocketError")
+        .impl_trait(format!("From<{}>", error_type))
+        .new_fn("from")
+        .arg("error", error_type)
+        .ret("Self")
+        .line("match error {");
+    for (_, variant) in errors.iter() {
+        into_socket.line(format!(
+            "    {}::{}(error) => SocketError::Signald(SignaldErrorKind::{}(error)),",
+            error_type, variant, variant
+        ));
+    }
+    into_socket
+        .line(format!("    {}::Unexpected(error) => SocketError::Signald(error),", error_type))
+        .line(format!("    {}::Socket(error) => error,", error_type))
+        .line("}");
 }
 
 fn add_types(
@@ -188,6 +836,7 @@ fn add_types(
     types: &Map<String, Value>,
     version: &str,
     variants: &mut Vec<Variant>,
+    requests: &HashSet<String>,
 ) {
     for (key, value) in types.iter() {
         let type_name = key.to_owned() + &version.to_uppercase();
@@ -197,30 +846,455 @@ fn add_types(
             format!("{}({})", type_name, type_name).as_str(),
         ));
 
+        let value = value.as_object().unwrap();
+        let fields = value.get("fields").unwrap().as_object().unwrap();
+        let is_request = version == "v1" && requests.contains(key);
+
         let new_struct = scope
             .new_struct((key.to_owned() + version.to_uppercase().as_str()).as_str())
             .vis("pub")
             .derive("Serialize")
             .derive("Deserialize")
-            .derive("Clone")
-            .derive("Default")
-            .derive("Debug");
-
-        let value = value.as_object().unwrap();
+            .derive("Clone");
+        // An empty id is no default, requests naming one are only built from their builder
+        if !has_required_id(&type_name, fields, is_request) {
+            new_struct.derive("Default");
+        }
+        new_struct.derive("Debug");
 
         if let Some(doc) = value.get("doc") {
             new_struct.doc(doc.as_str().unwrap());
         }
 
-        let fields = value.get("fields").unwrap().as_object().unwrap();
-
         for (field, info) in fields.iter() {
-            new_struct.push_field(get_field(field, info));
+            new_struct.push_field(get_field(&type_name, field, info, is_request));
+        }
+
+        if is_request && !fields.is_empty() {
+            add_builder(scope, &type_name, fields);
         }
     }
 }
 
-fn get_field(field: &String, info: &Value) -> Field {
+/// Generate a builder for a request type that takes every required field up front
+fn add_builder(scope: &mut Scope, type_name: &str, fields: &Map<String, Value>) {
+    let builder_name = format!("{}Builder", type_name);
+    let (required, optional): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .partition(|(_, info)| info["required"].as_bool().unwrap_or(false));
+
+    let builder_fn = scope
+        .new_impl(type_name)
+        .new_fn("builder")
+        .vis("pub")
+        .ret(&builder_name)
+        .doc(&format!("Start building a {} from its required fields", type_name));
+
+    for (field, info) in required.iter() {
+        builder_fn.arg(&get_field_name(field), format!("impl Into<{}>", get_field_type(type_name, field, info, true)));
+    }
+
+    builder_fn
+        .line(format!("{} {{", builder_name))
+        .line(format!("    inner: {} {{", type_name));
+    for (field, _) in required.iter() {
+        let name = get_field_name(field);
+        builder_fn.line(format!("        {}: {}.into(),", name, name));
+    }
+    if has_required_id(type_name, fields, true) {
+        for (field, _) in optional.iter() {
+            builder_fn.line(format!("        {}: None,", get_field_name(field)));
+        }
+    } else if !optional.is_empty() {
+        builder_fn.line("        ..Default::default()");
+    }
+    builder_fn.line("    },").line("}");
+
+    scope
+        .new_struct(&builder_name)
+        .vis("pub")
+        .derive("Clone")
+        .derive("Debug")
+        .doc(&format!("Builder for [`{}`], see [`{}::builder`]", type_name, type_name))
+        .field("inner", type_name);
+
+    let builder_impl = scope.new_impl(&builder_name);
+
+    for (field, info) in optional.iter() {
+        let name = get_field_name(field);
+        let setter = builder_impl
+            .new_fn(&name)
+            .vis("pub")
+            .arg_self()
+            .arg(&name, format!("impl Into<{}>", get_field_type(type_name, field, info, true)))
+            .ret("Self");
+
+        if let Some(doc) = info["doc"].as_str() {
+            setter.doc(doc);
+        }
+
+        setter
+            .line("let mut inner = self.inner;")
+            .line(format!("inner.{} = Some({}.into());", name, name))
+            .line(format!("{} {{ inner }}", builder_name));
+    }
+
+    builder_impl
+        .new_fn("build")
+        .vis("pub")
+        .arg_self()
+        .ret(type_name)
+        .line("self.inner");
+}
+
+/// Generate a test per type that builds it from the examples in the protocol document and
+/// checks the JSON it serializes to uses the field names signald expects
+fn add_example_tests(scope: &mut Scope, types: &Map<String, Value>, version: &str, requests: &HashSet<String>) {
+    for (key, value) in types.iter() {
+        let type_name = key.to_owned() + &version.to_uppercase();
+        let fields = value["fields"].as_object().unwrap();
+        let examples: Vec<(&String, &Value, String)> = fields
+            .iter()
+            .filter_map(|(field, info)| get_example(info).map(|example| (field, info, example)))
+            .collect();
+
+        if examples.is_empty() {
+            continue;
+        }
+
+        let is_request = version == "v1" && requests.contains(key);
+        let has_default = !has_required_id(&type_name, fields, is_request);
+        let missing: Vec<(&String, &Value)> = fields
+            .iter()
+            .filter(|(field, _)| !examples.iter().any(|(example, _, _)| example == field))
+            .collect();
+
+        let test_fn = scope
+            .new_fn(&format!("{}_{}_examples", key.to_case(Case::Snake), version))
+            .attr("test")
+            .allow("deprecated")
+            .line(format!("let value = {} {{", type_name));
+
+        for (field, info, example) in examples.iter() {
+            let example = format!("serde_json::from_str({:?}).unwrap()", example);
+            if info["required"].as_bool().unwrap_or(false) {
+                test_fn.line(format!("    {}: {},", get_field_name(field), example));
+            } else {
+                test_fn.line(format!("    {}: Some({}),", get_field_name(field), example));
+            }
+        }
+        if !has_default {
+            // Fields without an example get a valid id or enum value, their default or None
+            for (field, info) in missing.iter() {
+                let field_type = get_field_type(&type_name, field, info, is_request);
+                let string_enum = STRING_ENUMS.iter().find(|string_enum| string_enum.name == field_type);
+                let value = match field_type.as_str() {
+                    _ if !info["required"].as_bool().unwrap_or(false) => String::from("None"),
+                    "AccountId" => String::from("crate::ids::AccountId::parse(\"+12024561414\").unwrap()"),
+                    "GroupId" => String::from("crate::ids::GroupId::parse(\"EdSqI90cS0UomDpgUXOlCoObWvQOXlH5G3Z2d3f4ayE=\").unwrap()"),
+                    _ if string_enum.is_some() => format!("{}::from({:?})", field_type, string_enum.unwrap().values[0]),
+                    _ => String::from("Default::default()"),
+                };
+                test_fn.line(format!("    {}: {},", get_field_name(field), value));
+            }
+        } else if !missing.is_empty() {
+            test_fn.line("    ..Default::default()");
+        }
+
+        test_fn
+            .line("};")
+            .line("")
+            .line("let json = serde_json::to_value(&value).unwrap();");
+        for (field, _, example) in examples.iter() {
+            test_fn.line(format!(
+                "assert_eq!(json[{:?}], serde_json::from_str::<Value>({:?}).unwrap());",
+                field, example
+            ));
+        }
+
+        test_fn
+            .line("")
+            .line(format!("let value: {} = serde_json::from_value(json.clone()).unwrap();", type_name))
+            .line("assert_eq!(serde_json::to_value(&value).unwrap(), json);");
+    }
+}
+
+/// A field's example as JSON. Some examples in the protocol document are bare strings,
+/// or a single element of a list.
+fn get_example(info: &Value) -> Option<String> {
+    let example = info["example"].as_str()?;
+    let example = serde_json::from_str(example).unwrap_or_else(|_| Value::String(example.to_owned()));
+
+    let example = match info["list"].as_bool() {
+        Some(true) if !example.is_array() => Value::Array(vec![example]),
+        _ => example,
+    };
+
+    Some(example.to_string())
+}
+
+/// Generate the enums for string fields, keeping values this crate doesn't know about in `Unknown`
+fn add_string_enums(scope: &mut Scope) {
+    for string_enum in STRING_ENUMS.iter() {
+        let name = string_enum.name;
+        // UNKNOWN is signald's own fallback, so it ends up in the Unknown variant as well
+        let values: Vec<(&str, String)> = string_enum
+            .values
+            .iter()
+            .filter(|value| !value.eq_ignore_ascii_case("unknown"))
+            .map(|value| (*value, value.to_case(Case::Pascal)))
+            .collect();
+
+        let new_enum = scope
+            .new_enum(name)
+            .vis("pub")
+            .derive("Clone")
+            .derive("Debug")
+            .derive("PartialEq")
+            .derive("Eq")
+            .derive("Hash")
+            .doc(string_enum.doc);
+        for (_, variant) in values.iter() {
+            new_enum.new_variant(variant);
+        }
+        new_enum.push_variant(Variant::new("Unknown(String)"));
+
+        let as_str = scope
+            .new_impl(name)
+            .new_fn("as_str")
+            .vis("pub")
+            .arg_ref_self()
+            .ret("&str")
+            .doc("The value signald uses on the wire")
+            .line("match self {");
+        for (value, variant) in values.iter() {
+            as_str.line(format!("    {}::{} => \"{}\",", name, variant, value));
+        }
+        as_str
+            .line(format!("    {}::Unknown(value) => value.as_str(),", name))
+            .line("}");
+
+        let from_str = scope
+            .new_impl(name)
+            .impl_trait("From<&str>")
+            .new_fn("from")
+            .arg("value", "&str")
+            .ret("Self")
+            .line("match value {");
+        for (value, variant) in values.iter() {
+            from_str.line(format!("    \"{}\" => {}::{},", value, name, variant));
+        }
+        from_str
+            .line(format!("    value => {}::Unknown(value.to_owned()),", name))
+            .line("}");
+
+        scope
+            .new_impl(name)
+            .impl_trait("Serialize")
+            .new_fn("serialize")
+            .generic("S")
+            .bound("S", "serde::Serializer")
+            .arg_ref_self()
+            .arg("serializer", "S")
+            .ret("Result<S::Ok, S::Error>")
+            .line("serializer.serialize_str(self.as_str())");
+
+        scope
+            .new_impl(name)
+            .generic("'de")
+            .impl_trait("Deserialize<'de>")
+            .new_fn("deserialize")
+            .generic("D")
+            .bound("D", "serde::Deserializer<'de>")
+            .arg("deserializer", "D")
+            .ret("Result<Self, D::Error>")
+            .line("Ok(String::deserialize(deserializer)?.as_str().into())");
+    }
+}
+
+fn add_error_kinds(scope: &mut Scope, types: &Map<String, Value>, version: &str) {
+    let errors: Vec<(String, String)> = types
+        .iter()
+        .filter(|(_, value)| value["error"].as_bool().unwrap_or(false))
+        .map(|(key, _)| {
+            let variant = key.strip_suffix("Error").unwrap_or(key).to_owned();
+            (key.to_owned(), variant)
+        })
+        .collect();
+
+    let kinds_enum = scope
+        .new_enum("SignaldErrorKind")
+        .vis("pub")
+        .derive("Clone")
+        .derive("Debug")
+        .doc("Typed representation of an error returned by signald");
+
+    for (key, variant) in errors.iter() {
+        kinds_enum.push_variant(Variant::new(
+            format!("{}({}{})", variant, key, version.to_uppercase()).as_str(),
+        ));
+    }
+    kinds_enum.push_variant(Variant::new("Unknown(SignaldError)"));
+
+    let kinds_impl = scope.new_impl("SignaldErrorKind");
+
+    let from_fn = kinds_impl
+        .new_fn("from_error")
+        .vis("pub")
+        .arg("error", "SignaldError")
+        .ret("SignaldErrorKind")
+        .doc("Parse the payload of a raw error response according to its error_type")
+        .line("let kind = match error.error_type.as_str() {");
+
+    for (key, variant) in errors.iter() {
+        from_fn.line(format!(
+            "    \"{}\" => serde_json::from_value(error.error.clone()).map(SignaldErrorKind::{}),",
+            key, variant
+        ));
+    }
+    from_fn
+        .line("    _ => return SignaldErrorKind::Unknown(error),")
+        .line("};")
+        .line("")
+        .line("kind.unwrap_or(SignaldErrorKind::Unknown(error))");
+
+    let type_fn = kinds_impl
+        .new_fn("error_type")
+        .vis("pub")
+        .arg_ref_self()
+        .ret("&str")
+        .doc("The error_type name signald uses for this error")
+        .line("match self {");
+
+    for (key, variant) in errors.iter() {
+        type_fn.line(format!("    SignaldErrorKind::{}(_) => \"{}\",", variant, key));
+    }
+    type_fn
+        .line("    SignaldErrorKind::Unknown(error) => error.error_type.as_str(),")
+        .line("}");
+
+    let message_fn = kinds_impl
+        .new_fn("message")
+        .vis("pub")
+        .arg_ref_self()
+        .ret("Option<&str>")
+        .doc("Human readable message attached to the error, if any")
+        .line("match self {");
+
+    for (_, variant) in errors.iter() {
+        message_fn.line(format!(
+            "    SignaldErrorKind::{}(error) => error.message.as_deref(),",
+            variant
+        ));
+    }
+    message_fn
+        .line("    SignaldErrorKind::Unknown(error) => error.message(),")
+        .line("}");
+
+    scope
+        .new_impl("SignaldErrorKind")
+        .impl_trait("From<SignaldError>")
+        .new_fn("from")
+        .arg("error", "SignaldError")
+        .ret("Self")
+        .line("SignaldErrorKind::from_error(error)");
+}
+
+fn add_client_events(scope: &mut Scope, types: &Map<String, Value>, version: &str) {
+    let events_enum = scope
+        .new_enum("ClientEvent")
+        .vis("pub")
+        .derive("Clone")
+        .derive("Debug")
+        .doc("Unsolicited message delivered to a client after subscribing to an account");
+
+    let events: Vec<&str> = CLIENT_EVENTS
+        .iter()
+        .copied()
+        .filter(|event| types.contains_key(*event))
+        .collect();
+
+    for event in events.iter() {
+        let mut variant = Variant::new(event);
+        variant
+            .named("account", "Option<String>")
+            .named("data", format!("{}{}", event, version.to_uppercase()));
+        events_enum.push_variant(variant);
+    }
+
+    let mut variant = Variant::new("Error");
+    variant
+        .named("account", "Option<String>")
+        .named("error", "SignaldErrorKind");
+    events_enum.push_variant(variant);
+
+    let mut variant = Variant::new("Connection");
+    variant.named("state", "ConnectionState");
+    events_enum.push_variant(variant);
+
+    let mut variant = Variant::new("Unknown");
+    variant
+        .named("account", "Option<String>")
+        .named("type_", "String")
+        .named("data", "Value");
+    events_enum.push_variant(variant);
+
+    let events_impl = scope.new_impl("ClientEvent");
+
+    let from_fn = events_impl
+        .new_fn("from_value")
+        .vis("pub")
+        .arg("mut wrapper", "Value")
+        .ret("Result<ClientEvent, serde_json::Error>")
+        .doc("Decode a ClientMessageWrapper received from signald")
+        .line("let account = wrapper.get(\"account\").and_then(Value::as_str).map(str::to_owned);")
+        .line("let type_ = wrapper.get(\"type\").and_then(Value::as_str).unwrap_or_default().to_owned();")
+        .line("let data = wrapper.get_mut(\"data\").map(Value::take).unwrap_or_default();")
+        .line("")
+        .line("if wrapper.get(\"error\").and_then(Value::as_bool).unwrap_or(false) {")
+        .line("    let error = SignaldError {")
+        .line("        id: String::new(),")
+        .line("        type_: type_.clone(),")
+        .line("        error: data,")
+        .line("        error_type: type_,")
+        .line("    };")
+        .line("    return Ok(ClientEvent::Error { account, error: error.into() });")
+        .line("}")
+        .line("")
+        .line("Ok(match type_.as_str() {");
+
+    for event in events.iter() {
+        from_fn.line(format!(
+            "    \"{}\" => ClientEvent::{} {{ account, data: serde_json::from_value(data)? }},",
+            event, event
+        ));
+    }
+    from_fn
+        .line("    _ => ClientEvent::Unknown { account, type_, data },")
+        .line("})");
+
+    let account_fn = events_impl
+        .new_fn("account")
+        .vis("pub")
+        .arg_ref_self()
+        .ret("Option<&str>")
+        .doc("The account this event was delivered for")
+        .line("match self {");
+
+    for event in events.iter() {
+        account_fn.line(format!(
+            "    ClientEvent::{} {{ account, .. }} => account.as_deref(),",
+            event
+        ));
+    }
+    account_fn
+        .line("    ClientEvent::Error { account, .. } => account.as_deref(),")
+        .line("    ClientEvent::Unknown { account, .. } => account.as_deref(),")
+        .line("    ClientEvent::Connection { .. } => None,")
+        .line("}");
+}
+
+fn get_field(type_name: &str, field: &str, info: &Value, is_request: bool) -> Field {
     let mut doc = Vec::new();
     let mut example = String::from("Example: ");
 
@@ -233,15 +1307,73 @@ fn get_field(field: &String, info: &Value) -> Field {
         doc.push(example.as_str());
     }
 
+    let required = info["required"].as_bool().unwrap_or(false);
+    let mut new_field = get_clean_field(field, &get_field_type(type_name, field, info, is_request), required, get_deprecation(info));
+
+    new_field.doc(doc);
+
+    new_field
+}
+
+/// The note for the `#[deprecated]` attribute of a type, field or action signald deprecated
+fn get_deprecation(value: &Value) -> Option<String> {
+    let removal_date = value["removal_date"].as_i64();
+    if !value["deprecated"].as_bool().unwrap_or(false) && removal_date.is_none() {
+        return None;
+    }
+
+    Some(match removal_date {
+        Some(date) => format!("signald will remove this after {}", format_date(date)),
+        None => String::from("deprecated by signald"),
+    })
+}
+
+/// An action's removal date as the `Option<&str>` argument of `report_deprecated`
+fn get_removal_date(value: &Value) -> String {
+    match value["removal_date"].as_i64() {
+        Some(date) => format!("Some(\"{}\")", format_date(date)),
+        None => String::from("None"),
+    }
+}
+
+/// Format a unix timestamp as a YYYY-MM-DD date
+fn format_date(timestamp: i64) -> String {
+    // Civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = timestamp.div_euclid(86400) + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// The rust type of a field, without the `Option` optional fields are wrapped in
+fn get_field_type(type_name: &str, field: &str, info: &Value, is_request: bool) -> String {
+    let string_enum = STRING_ENUMS
+        .iter()
+        .find(|string_enum| string_enum.fields.contains(&(type_name, field)));
+    // Identifiers in requests are validated before they are sent
+    let id = ID_FIELDS
+        .iter()
+        .find(|(name, _)| is_request && *name == field && info["type"] == "String");
+
     let new_field = match info["version"].as_str() {
+        None if string_enum.is_some() => string_enum.unwrap().name.to_owned(),
+        None if id.is_some() => id.unwrap().1.to_owned(),
         Some(version) => {
             let version = version.to_uppercase();
             info["type"].as_str().unwrap().to_owned() + version.as_str()
         }
+        None if is_timestamp(type_name, field, info) => String::from("Timestamp"),
         None => get_type(info["type"].as_str().unwrap()),
     };
 
-    let new_field = match info["list"].as_bool() {
+    match info["list"].as_bool() {
         Some(list) => {
             if list {
                 format!("Vec<{}>", new_field)
@@ -250,41 +1382,65 @@ fn get_field(field: &String, info: &Value) -> Field {
             }
         }
         None => new_field,
-    };
-
-    let mut new_field = get_clean_field(field.as_str().clone(), &new_field);
+    }
+}
 
-    new_field.doc(doc);
+/// Whether a type has a required account or group id, which has no sensible default
+fn has_required_id(type_name: &str, fields: &Map<String, Value>, is_request: bool) -> bool {
+    fields.iter().any(|(field, info)| {
+        info["required"].as_bool().unwrap_or(false)
+            && matches!(get_field_type(type_name, field, info, is_request).as_str(), "AccountId" | "GroupId")
+    })
+}
 
-    new_field
+/// The rust name of a field, avoiding keywords
+fn get_field_name(name: &str) -> String {
+    match name.to_case(Case::Snake).as_str() {
+        "async" => String::from("async_"),
+        "type" => String::from("type_"),
+        converted => converted.to_owned(),
+    }
 }
 
-fn get_clean_field(name: &str, ty: &str) -> Field {
-    let mut annotations = vec![r#"#[serde(skip_serializing_if = "Option::is_none")]"#.to_owned()];
-    let converted = &name.to_case(Case::Snake);
+fn get_clean_field(name: &str, ty: &str, required: bool, deprecation: Option<String>) -> Field {
+    let mut annotations = Vec::new();
+    if !required {
+        annotations.push(r#"#[serde(skip_serializing_if = "Option::is_none")]"#.to_owned());
+    }
+    if let Some(note) = deprecation {
+        annotations.push(format!(r#"#[deprecated(note = "{}")]"#, note));
+    }
+
+    let converted = get_field_name(name);
     if converted.as_str() != name {
         annotations.push(format!(r#"#[serde(rename = "{}")]"#, name));
     }
 
-    let name = String::from("pub ") + converted;
-    let mut field = match name.as_str() {
-        "pub async" => {
-            let field = Field::new("pub async_", format!("Option<{}>", ty));
-            annotations.push(r#"#[serde(rename = "async")]"#.to_owned());
-            field
-        }
-        "pub type" => {
-            let field = Field::new("pub type_", format!("Option<{}>", ty));
-            annotations.push(r#"#[serde(rename = "type")]"#.to_owned());
-            field
-        }
-        _ => Field::new(name.as_str(), format!("Option<{}>", ty)),
+    let ty = if required {
+        ty.to_owned()
+    } else {
+        format!("Option<{}>", ty)
     };
+    let mut field = Field::new(format!("pub {}", converted).as_str(), ty);
 
     field.annotation(annotations.iter().map(|string| string.as_str()).collect());
     field
 }
 
+/// Whether a field holds milliseconds since the epoch. The protocol document has no
+/// type for these, so go by the field name.
+fn is_timestamp(type_name: &str, field: &str, info: &Value) -> bool {
+    let is_long = matches!(info["type"].as_str(), Some("long") | Some("Long"));
+    let name = field.to_case(Case::Snake);
+    // Type names carry their version, like JsonQuoteV1
+    let type_name = type_name.trim_end_matches(|c: char| c.is_ascii_digit()).strip_suffix('V').unwrap_or(type_name);
+    is_long
+        && (name.ends_with("timestamp")
+            || name.ends_with("timestamps")
+            || TIMESTAMP_FIELDS.contains(&name.as_str())
+            || TIMESTAMP_TYPE_FIELDS.contains(&(type_name, field)))
+}
+
 fn get_type(type_name: &str) -> String {
     String::from(match type_name {
         "String" => "String",
@@ -292,11 +1448,11 @@ fn get_type(type_name: &str) -> String {
         "Integer" => "i32",
         "long" => "i64",
         "Long" => "i64",
-        "Map" => "HashMap<String, String>",
-        "Object" => "HashMap<String, String>",
+        "Map" => "HashMap<String, Value>",
+        "Object" => "Value",
         "boolean" => "bool",
         "Boolean" => "bool",
-        "UUID" => "String",
+        "UUID" => "Uuid",
         _ => panic!("Failed to parse protocol doc: invalid type"),
     })
 }
@@ -0,0 +1,55 @@
+use crate::actions::SocketWrapper;
+use crate::errors::IdError;
+use crate::ids::AccountId;
+use crate::socket::AsyncSocket;
+use crate::SocketError;
+
+/// The actions of a socket on behalf of one account.
+///
+/// Requests sent through the handle have to be for its account: each action has a
+/// `<action>_request` builder with the account filled in, and an optional account left
+/// unset is filled in when sending. Requests naming another account fail with
+/// `SocketError::General` instead of being sent.
+#[derive(Clone)]
+pub struct AccountHandle<T> {
+    pub signald: SocketWrapper<T>,
+    account: AccountId,
+}
+
+impl<T: AsyncSocket> AccountHandle<T> {
+    pub fn new(signald: SocketWrapper<T>, account: AccountId) -> Self {
+        AccountHandle { signald, account }
+    }
+
+    pub fn id(&self) -> &AccountId {
+        &self.account
+    }
+
+    /// Fill in an optional account field left unset, or make sure it is ours
+    pub(crate) fn claim_optional(&self, account: &mut Option<AccountId>) -> Result<(), SocketError> {
+        self.check(account.get_or_insert_with(|| self.account.clone()))
+    }
+
+    /// Make sure an optional account field is unset or ours
+    pub(crate) fn check_optional(&self, account: &Option<AccountId>) -> Result<(), SocketError> {
+        match account {
+            Some(account) => self.check(account),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn check(&self, account: &AccountId) -> Result<(), SocketError> {
+        if *account == self.account {
+            Ok(())
+        } else {
+            Err(SocketError::General("Request is for a different account than its handle"))
+        }
+    }
+}
+
+impl<T: AsyncSocket + Clone> SocketWrapper<T> {
+    /// Act as the account with the given phone number or uuid
+    pub fn account(&self, account: &str) -> Result<AccountHandle<T>, IdError> {
+        Ok(AccountHandle::new(self.clone(), AccountId::parse(account)?))
+    }
+}
@@ -0,0 +1,679 @@
+use async_trait::async_trait;
+use futures::channel::{mpsc, oneshot};
+use futures::lock::Mutex as AsyncMutex;
+use futures::{Stream, StreamExt};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::future::{poll_fn, Future};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::pin::{pin, Pin};
+use std::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::account::AccountHandle;
+use crate::actions::{SocketWrapper, SubscribeError};
+use crate::ids::AccountId;
+use crate::runtime::Runtime;
+use crate::socket::{subscription_change, AsyncSocket, ConnectionState, Reconnect, Timeout, DEFAULT_TIMEOUT};
+use crate::types::{ClientEvent, SubscribeRequestV1, UnsubscribeRequestV1};
+use crate::SocketError;
+
+/// A request waiting for its response
+struct Request {
+    sender: oneshot::Sender<Value>,
+    /// Kept around to resend the request after a reconnect
+    buf: Option<Vec<u8>>,
+    /// Set once the request was written, by its caller or by a replay
+    written: bool,
+}
+
+type Map = Arc<Mutex<HashMap<Uuid, Request>>>;
+
+type Events = mpsc::UnboundedSender<Result<ClientEvent, SocketError>>;
+
+/// Where the listener delivers events, shared by every handle
+#[derive(Default)]
+struct Router {
+    /// Streams of every event
+    all: Vec<Events>,
+    /// Streams of the events for one account, keyed by the account as signald reports it
+    accounts: HashMap<String, Vec<Events>>,
+    /// Set once the connection is gone for good, new streams end right away
+    closed: bool,
+    /// Accounts being unsubscribed because their last stream was dropped
+    unsubscribing: HashSet<String>,
+}
+
+type Routes = Arc<Mutex<Router>>;
+
+impl Router {
+    fn stream(&mut self, account: Option<&AccountId>) -> Subscriber {
+        let (sender, receiver) = mpsc::unbounded();
+        if !self.closed {
+            match account {
+                Some(account) => self.accounts.entry(account.as_str().to_owned()).or_default().push(sender),
+                None => self.all.push(sender),
+            }
+        }
+
+        Subscriber { receiver, on_drop: None }
+    }
+
+    /// Deliver an event to the catch-all streams and, if it is for an account, that
+    /// account's streams. Connection state changes go to every stream.
+    fn route(&mut self, event: Result<ClientEvent, SocketError>) {
+        self.all.retain(|sender| !sender.is_closed());
+        for senders in self.accounts.values_mut() {
+            senders.retain(|sender| !sender.is_closed());
+        }
+        self.accounts.retain(|_, senders| !senders.is_empty());
+
+        let mut targets: Vec<&Events> = self.all.iter().collect();
+        match &event {
+            Ok(ClientEvent::Connection { .. }) => targets.extend(self.accounts.values().flatten()),
+            Ok(event) => {
+                if let Some(senders) = event.account().and_then(|account| self.accounts.get(account)) {
+                    targets.extend(senders);
+                }
+            }
+            Err(_) => {}
+        }
+
+        // A closed stream only means nobody is interested in the event
+        if let Some((last, rest)) = targets.split_last() {
+            for sender in rest {
+                let _ = sender.unbounded_send(duplicate(&event));
+            }
+            let _ = last.unbounded_send(event);
+        }
+    }
+
+    /// End every stream
+    fn close(&mut self) {
+        self.closed = true;
+        self.all.clear();
+        self.accounts.clear();
+    }
+
+    fn has_streams(&self, account: &str) -> bool {
+        self.accounts
+            .get(account)
+            .is_some_and(|senders| senders.iter().any(|sender| !sender.is_closed()))
+    }
+}
+
+/// Copy an event for another stream. Errors can't be cloned, so I/O and JSON
+/// errors are copied as their message.
+fn duplicate(event: &Result<ClientEvent, SocketError>) -> Result<ClientEvent, SocketError> {
+    match event {
+        Ok(event) => Ok(event.clone()),
+        Err(SocketError::General(e)) => Err(SocketError::General(e)),
+        Err(SocketError::Io(e)) => Err(SocketError::Io(std::io::Error::new(e.kind(), e.to_string()))),
+        Err(SocketError::Channel(e)) => Err(SocketError::Channel(e)),
+        Err(SocketError::Json(e)) => Err(SocketError::Json(serde::de::Error::custom(e))),
+        Err(SocketError::Closed) => Err(SocketError::Closed),
+        Err(SocketError::Timeout) => Err(SocketError::Timeout),
+        Err(SocketError::Signald(e)) => Err(SocketError::Signald(e.clone())),
+    }
+}
+
+/// Stream of the unsolicited messages signald sends on the connection, either
+/// all of them or the ones for a single account. It ends after the final
+/// `ConnectionState::Closed` event.
+pub struct Subscriber {
+    receiver: mpsc::UnboundedReceiver<Result<ClientEvent, SocketError>>,
+    /// Runs once the stream is dropped, after it stopped receiving events
+    on_drop: Option<Box<dyn FnOnce() + Send + Sync>>,
+}
+
+impl Subscriber {
+    /// Wait for the next event, `None` once the connection is closed
+    pub async fn recv(&mut self) -> Option<Result<ClientEvent, SocketError>> {
+        self.receiver.next().await
+    }
+}
+
+impl Drop for Subscriber {
+    fn drop(&mut self) {
+        self.receiver.close();
+        if let Some(on_drop) = self.on_drop.take() {
+            on_drop();
+        }
+    }
+}
+
+impl Stream for Subscriber {
+    type Item = Result<ClientEvent, SocketError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+/// The writing half of the socket, shared by every handle
+struct Connection<R: Runtime> {
+    /// `None` while a supervised connection is reconnecting
+    writer: AsyncMutex<Option<R::Writer>>,
+    /// Tells the background task to stop, dropping it has the same effect
+    closed: mpsc::UnboundedSender<()>,
+    /// Accounts whose last stream was dropped, for `unsubscribe_dropped` to unsubscribe
+    dropped: mpsc::UnboundedSender<AccountId>,
+}
+
+/// Cheaply cloneable handle to a signald connection. Every clone shares the
+/// same socket, so requests from many tasks can be in flight at once. The
+/// connection is closed once the last handle is dropped.
+pub struct Socket<R: Runtime> {
+    socket: Arc<Connection<R>>,
+    response_map: Map,
+    listening: Arc<Mutex<bool>>,
+    subscriptions: Arc<Mutex<HashSet<String>>>,
+    routes: Routes,
+    reconnect: Option<Reconnect>,
+    /// Shared by every handle, see `set_timeout`
+    timeout: Arc<Mutex<Option<Duration>>>,
+}
+
+impl<R: Runtime> Clone for Socket<R> {
+    fn clone(&self) -> Self {
+        Socket {
+            socket: self.socket.clone(),
+            response_map: self.response_map.clone(),
+            listening: self.listening.clone(),
+            subscriptions: self.subscriptions.clone(),
+            routes: self.routes.clone(),
+            reconnect: self.reconnect.clone(),
+            timeout: self.timeout.clone(),
+        }
+    }
+}
+
+impl<R: Runtime> Socket<R> {
+    fn downgrade(&self) -> WeakSocket<R> {
+        WeakSocket {
+            socket: Arc::downgrade(&self.socket),
+            response_map: self.response_map.clone(),
+            listening: self.listening.clone(),
+            subscriptions: self.subscriptions.clone(),
+            routes: self.routes.clone(),
+            reconnect: self.reconnect.clone(),
+            timeout: self.timeout.clone(),
+        }
+    }
+}
+
+/// Handle for the background task that doesn't keep the socket open by itself
+struct WeakSocket<R: Runtime> {
+    socket: Weak<Connection<R>>,
+    response_map: Map,
+    listening: Arc<Mutex<bool>>,
+    subscriptions: Arc<Mutex<HashSet<String>>>,
+    routes: Routes,
+    reconnect: Option<Reconnect>,
+    timeout: Arc<Mutex<Option<Duration>>>,
+}
+
+impl<R: Runtime> WeakSocket<R> {
+    fn upgrade(&self) -> Option<Socket<R>> {
+        Some(Socket {
+            socket: self.socket.upgrade()?,
+            response_map: self.response_map.clone(),
+            listening: self.listening.clone(),
+            subscriptions: self.subscriptions.clone(),
+            routes: self.routes.clone(),
+            reconnect: self.reconnect.clone(),
+            timeout: self.timeout.clone(),
+        })
+    }
+}
+
+/// Removes a pending request from the response map however the request ends
+struct Pending<'a> {
+    map: &'a Map,
+    id: Uuid,
+}
+
+impl Drop for Pending<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut map) = self.map.lock() {
+            map.remove(&self.id);
+        }
+    }
+}
+
+#[async_trait]
+impl<R: Runtime> AsyncSocket for Socket<R> {
+    async fn request<'a>(&'a self, buf: &'a [u8], id: Uuid, timeout: Timeout) -> Result<Value, SocketError> {
+        let replay = self.reconnect.as_ref().is_some_and(|r| r.replay_pending);
+
+        let (sender, receiver) = oneshot::channel();
+        {
+            let mut map = self.response_map.lock().unwrap();
+            if !*self.listening.lock().unwrap() {
+                return Err(SocketError::Closed);
+            }
+            let buf = if replay { Some(buf.to_vec()) } else { None };
+            map.insert(id, Request { sender, buf, written: false });
+        }
+        let _pending = Pending {
+            map: &self.response_map,
+            id,
+        };
+
+        // Replayed requests are written by the supervisor once the socket is back. It
+        // may already have done so while this was waiting for the writer.
+        let mut writer = self.socket.writer.lock().await;
+        let unwritten = self.response_map.lock().unwrap().get(&id).is_some_and(|request| !request.written);
+        if unwritten {
+            match writer.as_mut() {
+                Some(writer) => match R::write_all(writer, buf).await {
+                    Err(_) if replay => {}
+                    result => {
+                        result?;
+                        if let Some(request) = self.response_map.lock().unwrap().get_mut(&id) {
+                            request.written = true;
+                        }
+                    }
+                },
+                None if replay => {}
+                None => return Err(SocketError::Closed),
+            }
+        }
+        drop(writer);
+
+        // The listener drops every pending sender once the connection is gone
+        let timeout = match timeout {
+            Timeout::Default => *self.timeout.lock().unwrap(),
+            Timeout::Never => None,
+            Timeout::After(timeout) => Some(timeout),
+        };
+        let response = match timeout {
+            Some(timeout) => race(receiver, R::sleep(timeout))
+                .await
+                .ok_or(SocketError::Timeout)?,
+            None => receiver.await,
+        }
+        .map_err(|_| SocketError::Closed)?;
+
+        match subscription_change(buf, &response) {
+            Some((true, account)) => {
+                self.subscriptions.lock().unwrap().insert(account);
+            }
+            Some((false, account)) => {
+                self.subscriptions.lock().unwrap().remove(&account);
+            }
+            None => {}
+        }
+
+        Ok(response)
+    }
+}
+
+impl<R: Runtime> Socket<R> {
+    pub async fn connect<P: AsRef<Path>>(path: P) -> Result<(Self, Subscriber), SocketError> {
+        Socket::connect_with(path, None).await
+    }
+
+    /// Connect to signald, reconnecting according to `reconnect` whenever the socket is lost
+    pub async fn connect_with<P: AsRef<Path>>(
+        path: P,
+        reconnect: Option<Reconnect>,
+    ) -> Result<(Self, Subscriber), SocketError> {
+        let path = path.as_ref().to_path_buf();
+        let (reader, writer) = R::connect(&path).await?;
+
+        let mut router = Router::default();
+        let subscriber = router.stream(None);
+        let (closed_tx, closed_rx) = mpsc::unbounded();
+        let (dropped_tx, dropped_rx) = mpsc::unbounded();
+
+        let socket_wrapper = Socket {
+            socket: Arc::new(Connection {
+                writer: AsyncMutex::new(Some(writer)),
+                closed: closed_tx,
+                dropped: dropped_tx,
+            }),
+            response_map: Arc::new(Mutex::new(HashMap::new())),
+            listening: Arc::new(Mutex::new(true)),
+            subscriptions: Arc::new(Mutex::new(HashSet::new())),
+            routes: Arc::new(Mutex::new(router)),
+            reconnect,
+            timeout: Arc::new(Mutex::new(Some(DEFAULT_TIMEOUT))),
+        };
+
+        R::spawn(supervise(path, socket_wrapper.downgrade(), reader, closed_rx));
+        R::spawn(unsubscribe_dropped(socket_wrapper.downgrade(), dropped_rx));
+
+        Ok((socket_wrapper, subscriber))
+    }
+
+    /// Close the connection: pending requests fail with `SocketError::Closed`, the
+    /// background task stops and the subscriber sees a final `ConnectionState::Closed`
+    pub async fn close(&self) -> Result<(), SocketError> {
+        {
+            let mut map = self.response_map.lock().unwrap();
+            *self.listening.lock().unwrap() = false;
+            map.clear();
+        }
+        self.socket.closed.close_channel();
+
+        match self.socket.writer.lock().await.take() {
+            Some(mut writer) => Ok(R::shutdown(&mut writer).await?),
+            None => Ok(()),
+        }
+    }
+
+    /// Set the timeout of requests made with `Timeout::Default`, for every handle of
+    /// the connection. `None` waits for signald indefinitely.
+    pub fn set_timeout(&self, timeout: Option<Duration>) {
+        *self.timeout.lock().unwrap() = timeout;
+    }
+
+    /// Accounts this connection is currently subscribed to
+    pub fn subscriptions(&self) -> Vec<String> {
+        self.subscriptions.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Another stream of every event, like the one returned when connecting
+    pub fn events(&self) -> Subscriber {
+        self.routes.lock().unwrap().stream(None)
+    }
+
+    /// Stream of the events for one account, along with connection state changes.
+    /// Events are matched by the account signald reports, which is the account as
+    /// it was subscribed.
+    pub fn account_events(&self, account: &AccountId) -> Subscriber {
+        self.routes.lock().unwrap().stream(Some(account))
+    }
+}
+
+/// Run the listener, reconnecting whenever it stops if the socket was configured to
+async fn supervise<R: Runtime>(
+    path: PathBuf,
+    weak: WeakSocket<R>,
+    mut reader: R::Reader,
+    mut closed: mpsc::UnboundedReceiver<()>,
+) {
+    let routes = weak.routes.clone();
+    let emit = |state| routes.lock().unwrap().route(Ok(ClientEvent::Connection { state }));
+
+    loop {
+        let listener = listen::<R>(&mut reader, &weak.response_map, &weak.listening, &weak.routes);
+        if race(listener, closed.next()).await.is_none() {
+            break;
+        }
+
+        let reconnect = match &weak.reconnect {
+            Some(reconnect) if *weak.listening.lock().unwrap() => reconnect,
+            _ => break,
+        };
+
+        match weak.socket.upgrade() {
+            Some(socket) => *socket.writer.lock().await = None,
+            None => break,
+        }
+        if !reconnect.replay_pending {
+            weak.response_map.lock().unwrap().clear();
+        }
+        emit(ConnectionState::Disconnected);
+
+        let mut attempt = 0;
+        let stream = loop {
+            attempt += 1;
+            if reconnect.max_attempts.is_some_and(|max| attempt > max) {
+                break None;
+            }
+
+            emit(ConnectionState::Reconnecting { attempt });
+            if race(R::sleep(reconnect.backoff(attempt)), closed.next()).await.is_none() {
+                break None;
+            }
+
+            match R::connect(&path).await {
+                Ok(stream) => break Some(stream),
+                Err(e) => routes.lock().unwrap().route(Err(SocketError::Io(e))),
+            }
+        };
+
+        let (new_reader, mut writer) = match stream {
+            Some(stream) => stream,
+            None => break,
+        };
+        let socket = match weak.upgrade() {
+            Some(socket) => socket,
+            None => break,
+        };
+
+        reader = new_reader;
+
+        // The listener has to be running again before pending requests are replayed, or
+        // their responses could fill the socket and block the replay. Requests made in
+        // the meantime still see no writer and are left to the replay.
+        let signald = SocketWrapper { socket };
+        let routes = weak.routes.clone();
+        R::spawn(async move {
+            {
+                let mut guard = signald.socket.socket.writer.lock().await;
+                let pending: Vec<Vec<u8>> = signald
+                    .socket
+                    .response_map
+                    .lock()
+                    .unwrap()
+                    .values_mut()
+                    .filter_map(|request| {
+                        request.written = true;
+                        request.buf.clone()
+                    })
+                    .collect();
+                for buf in pending {
+                    if let Err(e) = R::write_all(&mut writer, &buf).await {
+                        routes.lock().unwrap().route(Err(SocketError::Io(e)));
+                    }
+                }
+                *guard = Some(writer);
+            }
+
+            for account in signald.socket.subscriptions() {
+                let request = SubscribeRequestV1 {
+                    account: AccountId::new_unchecked(account),
+                };
+                if let Err(e) = signald.subscribe(request, None, Timeout::Default).await {
+                    routes.lock().unwrap().route(Err(e.into()));
+                }
+            }
+
+            let state = ConnectionState::Connected;
+            routes.lock().unwrap().route(Ok(ClientEvent::Connection { state }));
+        });
+    }
+
+    // Fail every pending request by dropping its sender
+    {
+        let mut map = weak.response_map.lock().unwrap();
+        *weak.listening.lock().unwrap() = false;
+        map.clear();
+    }
+    if let Some(socket) = weak.socket.upgrade() {
+        *socket.writer.lock().await = None;
+    }
+    emit(ConnectionState::Closed);
+    weak.routes.lock().unwrap().close();
+}
+
+/// Unsubscribe the accounts whose last stream was dropped. Streams can be dropped
+/// outside of the runtime, so they leave spawning the unsubscribe to this task.
+async fn unsubscribe_dropped<R: Runtime>(weak: WeakSocket<R>, mut dropped: mpsc::UnboundedReceiver<AccountId>) {
+    while let Some(account) = dropped.next().await {
+        match weak.upgrade() {
+            Some(socket) => R::spawn(unsubscribe(SocketWrapper { socket }, account)),
+            None => break,
+        }
+    }
+}
+
+async fn unsubscribe<R: Runtime>(signald: SocketWrapper<Socket<R>>, account: AccountId) {
+    // A stream opened since the drop keeps the subscription
+    let unsubscribe = !signald.socket.routes.lock().unwrap().has_streams(account.as_str());
+    if unsubscribe {
+        let request = UnsubscribeRequestV1 { account: account.clone() };
+        if let Err(e) = signald.unsubscribe(request, None, Timeout::Default).await {
+            signald.socket.routes.lock().unwrap().route(Err(e.into()));
+        }
+    }
+
+    // A stream opened while unsubscribing may have subscribed before the
+    // unsubscribe was sent, so subscribe again for it
+    let resubscribe = {
+        let mut routes = signald.socket.routes.lock().unwrap();
+        routes.unsubscribing.remove(account.as_str());
+        unsubscribe && routes.has_streams(account.as_str())
+    };
+    if resubscribe {
+        let request = SubscribeRequestV1 { account };
+        if let Err(e) = signald.subscribe(request, None, Timeout::Default).await {
+            signald.socket.routes.lock().unwrap().route(Err(e.into()));
+        }
+    }
+}
+
+/// Runs `future` to completion unless `stop` completes first
+async fn race<F: Future, S: Future>(future: F, stop: S) -> Option<F::Output> {
+    let mut future = pin!(future);
+    let mut stop = pin!(stop);
+
+    poll_fn(|cx| {
+        if stop.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(None);
+        }
+        future.as_mut().poll(cx).map(Some)
+    })
+    .await
+}
+
+async fn listen<R: Runtime>(reader: &mut R::Reader, map: &Map, listening: &Mutex<bool>, routes: &Routes) {
+    let mut buf = String::with_capacity(1024);
+
+    while *listening.lock().unwrap() {
+        match R::read_line(reader, &mut buf).await {
+            Ok(0) => break,
+            Ok(_) => match dispatch(buf.as_str(), map) {
+                Ok(Some(event)) => routes.lock().unwrap().route(Ok(event)),
+                Ok(None) => {}
+                Err(e) => routes.lock().unwrap().route(Err(e)),
+            },
+            Err(e) if e.kind() == ErrorKind::InvalidData => {
+                routes.lock().unwrap().route(Err(SocketError::Io(e)));
+            }
+            Err(e) => {
+                routes.lock().unwrap().route(Err(SocketError::Io(e)));
+                break;
+            }
+        }
+
+        buf.clear();
+    }
+}
+
+fn dispatch(line: &str, map: &Map) -> Result<Option<ClientEvent>, SocketError> {
+    let response: Value = serde_json::from_str(line)?;
+
+    if let Some(id) = response.get("id") {
+        let id = id
+            .as_str()
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .ok_or(SocketError::General("Received response with an invalid id"))?;
+
+        // Requests that timed out or were dropped are already gone from the map
+        if let Some(request) = map.lock().unwrap().remove(&id) {
+            let _ = request.sender.send(response);
+        }
+
+        return Ok(None);
+    }
+
+    Ok(Some(ClientEvent::from_value(response)?))
+}
+
+impl<R: Runtime> SocketWrapper<Socket<R>> {
+    /// Connect to signald, returning the client and the stream of events it receives
+    pub async fn connect<P: AsRef<Path>>(path: P) -> Result<(Self, Subscriber), SocketError> {
+        let (socket, subscriber) = Socket::connect(path).await?;
+        Ok((SocketWrapper { socket }, subscriber))
+    }
+
+    /// Connect to signald with a supervisor that reconnects and restores
+    /// subscriptions whenever signald goes away
+    pub async fn connect_with_reconnect<P: AsRef<Path>>(
+        path: P,
+        reconnect: Reconnect,
+    ) -> Result<(Self, Subscriber), SocketError> {
+        let (socket, subscriber) = Socket::connect_with(path, Some(reconnect)).await?;
+        Ok((SocketWrapper { socket }, subscriber))
+    }
+
+    /// Shut the connection down, unsubscribing every subscribed account first if asked to.
+    /// The connection is closed even when unsubscribing fails.
+    pub async fn shutdown(&self, unsubscribe: bool) -> Result<(), SocketError> {
+        let mut result = Ok(());
+        if unsubscribe {
+            for account in self.socket.subscriptions() {
+                let request = UnsubscribeRequestV1 {
+                    account: AccountId::new_unchecked(account),
+                };
+                if let Err(e) = self.unsubscribe(request, None, Timeout::Default).await {
+                    result = result.and(Err(e.into()));
+                }
+            }
+        }
+
+        let closed = self.socket.close().await;
+        result.and(closed)
+    }
+
+    /// Subscribe to an account unless the connection already is, and return the stream
+    /// of its events. With `unsubscribe_on_drop` the account is unsubscribed once its
+    /// last stream is dropped.
+    pub async fn subscribe_events(&self, account: AccountId, unsubscribe_on_drop: bool) -> Result<Subscriber, SubscribeError> {
+        // Opened first so no event between subscribing and returning is lost, and an
+        // unsubscribe that is about to be sent for a dropped stream is called off
+        let mut subscriber = self.socket.account_events(&account);
+        let unsubscribing = self.socket.routes.lock().unwrap().unsubscribing.contains(account.as_str());
+        if unsubscribing || !self.socket.subscriptions.lock().unwrap().contains(account.as_str()) {
+            let request = SubscribeRequestV1 {
+                account: account.clone(),
+            };
+            self.subscribe(request, None, Timeout::Default).await?;
+        }
+
+        if unsubscribe_on_drop {
+            let weak = self.socket.downgrade();
+            subscriber.on_drop = Some(Box::new(move || {
+                let socket = match weak.upgrade() {
+                    Some(socket) => socket,
+                    None => return,
+                };
+                let mut routes = socket.routes.lock().unwrap();
+                if routes.has_streams(account.as_str()) {
+                    return;
+                }
+                routes.unsubscribing.insert(account.as_str().to_owned());
+                if socket.socket.dropped.unbounded_send(account.clone()).is_err() {
+                    routes.unsubscribing.remove(account.as_str());
+                }
+            }));
+        }
+
+        Ok(subscriber)
+    }
+}
+
+impl<R: Runtime> AccountHandle<Socket<R>> {
+    /// Stream of this account's events, subscribing to it unless the connection already is.
+    /// See `SocketWrapper::subscribe_events`.
+    pub async fn subscribe_events(&self, unsubscribe_on_drop: bool) -> Result<Subscriber, SubscribeError> {
+        self.signald.subscribe_events(self.id().clone(), unsubscribe_on_drop).await
+    }
+}
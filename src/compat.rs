@@ -0,0 +1,167 @@
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+use crate::actions::SocketWrapper;
+use crate::socket::{request_data, AsyncSocket, Timeout};
+use crate::types::VersionRequestV1;
+use crate::SocketError;
+
+/// The protocol document this crate was generated from
+pub const PROTOCOL: &str = include_str!(concat!(env!("OUT_DIR"), "/protocol.json"));
+
+/// Differences between the protocol this crate was compiled against and the one
+/// the running daemon describes. Types are named like the generated structs, e.g. `JsonAddressV1`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Compatibility {
+    pub compiled_version: Option<String>,
+    pub daemon_version: Option<String>,
+    /// Compiled actions the daemon doesn't know
+    pub missing_actions: Vec<String>,
+    /// Actions whose request or response type differs
+    pub changed_actions: Vec<String>,
+    /// Daemon actions this crate has no bindings for
+    pub new_actions: Vec<String>,
+    pub missing_types: Vec<String>,
+    /// Types whose fields differ
+    pub changed_types: Vec<String>,
+    pub new_types: Vec<String>,
+}
+
+impl Compatibility {
+    /// Compare two protocol documents
+    pub fn compare(compiled: &Value, daemon: &Value) -> Self {
+        let (missing_actions, changed_actions, new_actions) = diff(
+            &entries(compiled, "actions"),
+            &entries(daemon, "actions"),
+            |compiled, daemon| {
+                compiled["request"] == daemon["request"] && compiled["response"] == daemon["response"]
+            },
+        );
+        let (missing_types, changed_types, new_types) = diff(
+            &entries(compiled, "types"),
+            &entries(daemon, "types"),
+            |compiled, daemon| same_fields(&compiled["fields"], &daemon["fields"]),
+        );
+
+        Compatibility {
+            compiled_version: version(compiled),
+            daemon_version: version(daemon),
+            missing_actions,
+            changed_actions,
+            new_actions,
+            missing_types,
+            changed_types,
+            new_types,
+        }
+    }
+
+    /// Whether every compiled action and type can be used with the daemon unchanged.
+    /// Additions on the daemon side don't affect compatibility.
+    pub fn is_compatible(&self) -> bool {
+        self.missing_actions.is_empty()
+            && self.changed_actions.is_empty()
+            && self.missing_types.is_empty()
+            && self.changed_types.is_empty()
+    }
+}
+
+/// Every action or type in a protocol document, keyed by its generated name
+fn entries<'a>(protocol: &'a Value, kind: &str) -> Vec<(String, &'a Value)> {
+    let versions = match protocol[kind].as_object() {
+        Some(versions) => versions,
+        None => return Vec::new(),
+    };
+
+    let mut entries = Vec::new();
+    for (version, items) in versions {
+        for (name, item) in items.as_object().into_iter().flatten() {
+            let name = match kind {
+                "actions" => name.to_owned(),
+                _ => name.to_owned() + &version.to_uppercase(),
+            };
+            entries.push((name, item));
+        }
+    }
+    entries
+}
+
+type Diff = (Vec<String>, Vec<String>, Vec<String>);
+
+fn diff<F>(compiled: &[(String, &Value)], daemon: &[(String, &Value)], same: F) -> Diff
+where
+    F: Fn(&Value, &Value) -> bool,
+{
+    let mut missing = BTreeSet::new();
+    let mut changed = BTreeSet::new();
+
+    for (name, compiled) in compiled {
+        match daemon.iter().find(|(daemon_name, _)| daemon_name == name) {
+            Some((_, daemon)) if !same(compiled, daemon) => {
+                changed.insert(name.to_owned());
+            }
+            Some(_) => {}
+            None => {
+                missing.insert(name.to_owned());
+            }
+        }
+    }
+
+    let new: BTreeSet<String> = daemon
+        .iter()
+        .filter(|(name, _)| !compiled.iter().any(|(compiled_name, _)| compiled_name == name))
+        .map(|(name, _)| name.to_owned())
+        .collect();
+
+    (
+        missing.into_iter().collect(),
+        changed.into_iter().collect(),
+        new.into_iter().collect(),
+    )
+}
+
+/// Whether two types have the same fields. Docs and examples don't affect the wire
+/// format, so only what does is compared.
+fn same_fields(compiled: &Value, daemon: &Value) -> bool {
+    let (compiled, daemon) = match (compiled.as_object(), daemon.as_object()) {
+        (Some(compiled), Some(daemon)) => (compiled, daemon),
+        _ => return compiled == daemon,
+    };
+
+    let flag = |field: &Value, name: &str| field[name].as_bool().unwrap_or(false);
+    compiled.len() == daemon.len()
+        && compiled.iter().all(|(name, compiled)| match daemon.get(name) {
+            Some(daemon) => {
+                compiled["type"] == daemon["type"]
+                    && compiled["version"] == daemon["version"]
+                    && flag(compiled, "list") == flag(daemon, "list")
+                    && flag(compiled, "required") == flag(daemon, "required")
+            }
+            None => false,
+        })
+}
+
+fn version(protocol: &Value) -> Option<String> {
+    protocol["version"]["version"].as_str().map(str::to_owned)
+}
+
+impl<T: AsyncSocket> SocketWrapper<T> {
+    /// Ask the running daemon for its protocol document and compare it with the one
+    /// this crate was compiled against
+    pub async fn check_compatibility(&self, timeout: Timeout) -> Result<Compatibility, SocketError> {
+        let compiled: Value = serde_json::from_str(PROTOCOL)?;
+        let daemon = self.protocol(timeout).await?;
+
+        let mut compatibility = Compatibility::compare(&compiled, &daemon);
+        if compatibility.daemon_version.is_none() {
+            let version = self.version(VersionRequestV1, None, timeout).await?;
+            compatibility.daemon_version = version.version;
+        }
+
+        Ok(compatibility)
+    }
+
+    /// The protocol document the running daemon describes itself with
+    pub async fn protocol(&self, timeout: Timeout) -> Result<Value, SocketError> {
+        request_data(&self.socket, "protocol", (), None, timeout).await
+    }
+}
@@ -1,16 +1,87 @@
 use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use std::fmt::{Debug, Display};
+use std::io::Error;
 
-#[derive(Serialize, Deserialize)]
-pub struct ErrorInner {
-    pub more: String,
-    pub message: String,
-}
+pub use crate::types::SignaldErrorKind;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct SignaldError {
     pub id: String,
     #[serde(rename = "type")]
     pub type_: String,
-    pub error: ErrorInner,
+    pub error: Value,
     pub error_type: String
 }
+
+impl SignaldError {
+    pub fn message(&self) -> Option<&str> {
+        self.error.get("message").and_then(|message| message.as_str())
+    }
+}
+
+/// Everything a request can fail with, whichever runtime the client runs on
+pub enum SocketError {
+    General(&'static str),
+    Io(Error),
+    Channel(&'static str),
+    Json(serde_json::Error),
+    Closed,
+    Timeout,
+    Signald(SignaldErrorKind)
+}
+
+impl Display for SocketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SocketError::General(desc) => write!(f, "Error: {}", desc),
+            SocketError::Io(e) => write!(f, "Error: {}", e),
+            SocketError::Channel(e) => write!(f, "Error: {}", e),
+            SocketError::Json(e) => write!(f, "Error: {}", e),
+            SocketError::Closed => write!(f, "Error: Connection to signald closed"),
+            SocketError::Timeout => write!(f, "Error: Timed out waiting for signald"),
+            SocketError::Signald(e) => write!(f, "Signald error: {}", e.message().unwrap_or_else(|| e.error_type()))
+        }
+    }
+}
+
+impl Debug for SocketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl From<Error> for SocketError {
+    fn from(e: Error) -> Self {
+        SocketError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for SocketError {
+    fn from(e: serde_json::Error) -> Self {
+        SocketError::Json(e)
+    }
+}
+
+/// An identifier that failed validation, carrying the rejected input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdError {
+    PhoneNumber(String),
+    Account(String),
+    GroupId(String),
+    /// An address needs a uuid, a phone number or both
+    EmptyAddress
+}
+
+impl Display for IdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdError::PhoneNumber(input) => write!(f, "Not an E.164 phone number: {}", input),
+            IdError::Account(input) => write!(f, "Not a phone number or account uuid: {}", input),
+            IdError::GroupId(input) => write!(f, "Not a base64 v2 group id: {}", input),
+            IdError::EmptyAddress => write!(f, "Address has neither a uuid nor a phone number")
+        }
+    }
+}
+
+impl std::error::Error for IdError {}
@@ -0,0 +1,317 @@
+use std::convert::TryFrom;
+
+use crate::ids::GroupId;
+use crate::recipient::Recipient;
+use crate::timestamp::Timestamp;
+#[cfg(signald_stories)]
+use crate::types::StoryMessageV1;
+use crate::types::{
+    CallMessageV1, ClientEvent, IncomingMessageV1, JsonAttachmentV1, JsonDataMessageV1, JsonGroupV2InfoV1, JsonMentionV1,
+    JsonQuoteV1, JsonSentTranscriptMessageV1, JsonSyncMessageV1, PaymentV1, ReceiptType, TypingAction,
+};
+
+/// Where a message belongs: a conversation with one person or a group
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Conversation {
+    Direct(Recipient),
+    Group(GroupId),
+    /// A legacy (v1) group, whose 16 byte ids aren't `GroupId`s
+    LegacyGroup(String),
+}
+
+/// What every event carries, whatever kind it is
+#[derive(Clone, Debug)]
+pub struct Envelope {
+    /// The subscribed account the message was delivered to
+    pub account: Option<String>,
+    pub sender: Recipient,
+    pub sender_device: Option<i32>,
+    /// The group for group messages, otherwise the other party: the sender, or the
+    /// recipient of messages sent from another device of the account
+    pub conversation: Conversation,
+    pub timestamp: Timestamp,
+}
+
+/// An incoming message, sorted by what it means instead of which of its fields are set
+#[derive(Clone, Debug)]
+pub enum Event {
+    /// A message with a body, attachments or both
+    Text {
+        envelope: Envelope,
+        body: Option<String>,
+        attachments: Vec<JsonAttachmentV1>,
+        mentions: Vec<JsonMentionV1>,
+        quote: Option<JsonQuoteV1>,
+        expires_in_seconds: Option<i32>,
+    },
+    Reaction {
+        envelope: Envelope,
+        emoji: String,
+        /// The reaction was taken back
+        remove: bool,
+        target_author: Recipient,
+        target_timestamp: Timestamp,
+    },
+    RemoteDelete {
+        envelope: Envelope,
+        target_timestamp: Timestamp,
+    },
+    Receipt {
+        envelope: Envelope,
+        /// The protocol's `UNKNOWN` if signald didn't say
+        kind: ReceiptType,
+        /// The messages being acknowledged
+        timestamps: Vec<Timestamp>,
+    },
+    Typing {
+        envelope: Envelope,
+        /// The protocol's `UNKNOWN` if signald didn't say
+        action: TypingAction,
+    },
+    Call {
+        envelope: Envelope,
+        call: Box<CallMessageV1>,
+    },
+    /// A message the account sent from another device
+    SyncSent {
+        envelope: Envelope,
+        body: Option<String>,
+        sent: Box<JsonSentTranscriptMessageV1>,
+    },
+    /// Any other sync message, like read markers or contact lists
+    Sync {
+        envelope: Envelope,
+        sync: Box<JsonSyncMessageV1>,
+    },
+    /// Only with protocols that have stories
+    #[cfg(signald_stories)]
+    Story {
+        envelope: Envelope,
+        story: Box<StoryMessageV1>,
+    },
+    /// A change to a v2 group, `group.group_change` says what changed
+    GroupUpdate {
+        envelope: Envelope,
+        group: Box<JsonGroupV2InfoV1>,
+    },
+    ExpirationUpdate {
+        envelope: Envelope,
+        /// `0` turns disappearing messages off
+        expires_in_seconds: i32,
+    },
+    Payment {
+        envelope: Envelope,
+        payment: PaymentV1,
+    },
+    /// Everything else, like end session messages and profile key updates
+    Other {
+        envelope: Envelope,
+        message: Box<IncomingMessageV1>,
+    },
+}
+
+impl Event {
+    /// Sort an incoming message. `None` if signald didn't say who sent it or when, a
+    /// sender with a uuid is kept even if its number is invalid.
+    pub fn from_incoming(mut message: IncomingMessageV1) -> Option<Event> {
+        let sender = Recipient::try_from(message.source.as_ref()?).ok()?;
+        let timestamp = message
+            .timestamp
+            .or_else(|| message.data_message.as_ref().and_then(|data| data.timestamp))
+            .or(message.server_receiver_timestamp)?;
+        let envelope = Envelope {
+            account: message.account.clone(),
+            conversation: Conversation::Direct(sender.clone()),
+            sender,
+            sender_device: message.source_device,
+            timestamp,
+        };
+
+        if let Some(data) = &message.data_message {
+            return Some(Event::from_data(envelope, data).unwrap_or_else(|envelope| Event::Other {
+                envelope,
+                message: Box::new(message),
+            }));
+        }
+        if let Some(sync) = message.sync_message.take() {
+            return Some(Event::from_sync(envelope, sync));
+        }
+        if let Some(receipt) = message.receipt_message.take() {
+            return Some(Event::Receipt {
+                envelope,
+                kind: receipt.type_.unwrap_or_else(|| ReceiptType::from("UNKNOWN")),
+                timestamps: receipt.timestamps.unwrap_or_default(),
+            });
+        }
+        if let Some(typing) = message.typing_message.take() {
+            return Some(Event::Typing {
+                envelope: in_group(envelope, typing.group_id),
+                action: typing.action.unwrap_or_else(|| TypingAction::from("UNKNOWN")),
+            });
+        }
+        if let Some(call) = message.call_message.take() {
+            return Some(Event::Call {
+                envelope,
+                call: Box::new(call),
+            });
+        }
+        #[cfg(signald_stories)]
+        if let Some(story) = message.story_message.take() {
+            let group = story.group.as_ref().and_then(|group| group.id.clone());
+            return Some(Event::Story {
+                envelope: in_group(envelope, group),
+                story: Box::new(story),
+            });
+        }
+
+        Some(Event::Other {
+            envelope,
+            message: Box::new(message),
+        })
+    }
+
+    /// Sort a data message, handing the envelope back if it is none of the known kinds
+    fn from_data(envelope: Envelope, data: &JsonDataMessageV1) -> Result<Event, Envelope> {
+        let envelope = in_group(envelope, group_id(data));
+
+        if let Some(reaction) = &data.reaction {
+            let target_author = reaction.target_author.as_ref().and_then(|author| Recipient::try_from(author).ok());
+            return match (target_author, reaction.target_sent_timestamp) {
+                (Some(target_author), Some(target_timestamp)) => Ok(Event::Reaction {
+                    envelope,
+                    emoji: reaction.emoji.clone().unwrap_or_default(),
+                    remove: reaction.remove.unwrap_or(false),
+                    target_author,
+                    target_timestamp,
+                }),
+                _ => Err(envelope),
+            };
+        }
+        if let Some(target_timestamp) = data.remote_delete.as_ref().and_then(|delete| delete.target_sent_timestamp) {
+            return Ok(Event::RemoteDelete {
+                envelope,
+                target_timestamp,
+            });
+        }
+        if let Some(payment) = &data.payment {
+            return Ok(Event::Payment {
+                envelope,
+                payment: payment.clone(),
+            });
+        }
+        if data.is_expiration_update.unwrap_or(false) {
+            return Ok(Event::ExpirationUpdate {
+                envelope,
+                expires_in_seconds: data.expires_in_seconds.unwrap_or(0),
+            });
+        }
+
+        let attachments = data.attachments.clone().unwrap_or_default();
+        if data.body.is_some() || !attachments.is_empty() {
+            return Ok(Event::Text {
+                envelope,
+                body: data.body.clone(),
+                attachments,
+                mentions: data.mentions.clone().unwrap_or_default(),
+                quote: data.quote.clone(),
+                expires_in_seconds: data.expires_in_seconds,
+            });
+        }
+        if let Some(group) = &data.group_v_2 {
+            return Ok(Event::GroupUpdate {
+                envelope,
+                group: Box::new(group.clone()),
+            });
+        }
+
+        Err(envelope)
+    }
+
+    fn from_sync(envelope: Envelope, mut sync: JsonSyncMessageV1) -> Event {
+        let sent = match sync.sent.take() {
+            Some(sent) => sent,
+            None => {
+                return Event::Sync {
+                    envelope,
+                    sync: Box::new(sync),
+                }
+            }
+        };
+
+        let mut envelope = in_group(envelope, sent.message.as_ref().and_then(group_id));
+        if let Conversation::Direct(_) = envelope.conversation {
+            if let Some(destination) = sent.destination.as_ref().and_then(|to| Recipient::try_from(to).ok()) {
+                envelope.conversation = Conversation::Direct(destination);
+            }
+        }
+        if let Some(timestamp) = sent.timestamp {
+            envelope.timestamp = timestamp;
+        }
+
+        Event::SyncSent {
+            envelope,
+            body: sent.message.as_ref().and_then(|data| data.body.clone()),
+            sent: Box::new(sent),
+        }
+    }
+
+    pub fn envelope(&self) -> &Envelope {
+        match self {
+            Event::Text { envelope, .. }
+            | Event::Reaction { envelope, .. }
+            | Event::RemoteDelete { envelope, .. }
+            | Event::Receipt { envelope, .. }
+            | Event::Typing { envelope, .. }
+            | Event::Call { envelope, .. }
+            | Event::SyncSent { envelope, .. }
+            | Event::Sync { envelope, .. }
+            | Event::GroupUpdate { envelope, .. }
+            | Event::ExpirationUpdate { envelope, .. }
+            | Event::Payment { envelope, .. }
+            | Event::Other { envelope, .. } => envelope,
+            #[cfg(signald_stories)]
+            Event::Story { envelope, .. } => envelope,
+        }
+    }
+
+    pub fn sender(&self) -> &Recipient {
+        &self.envelope().sender
+    }
+
+    pub fn conversation(&self) -> &Conversation {
+        &self.envelope().conversation
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.envelope().timestamp
+    }
+}
+
+impl ClientEvent {
+    /// The normalized event of an incoming message, see `Event::from_incoming`
+    pub fn into_event(self) -> Option<Event> {
+        match self {
+            ClientEvent::IncomingMessage { data, .. } => Event::from_incoming(data),
+            _ => None,
+        }
+    }
+}
+
+#[allow(deprecated)]
+fn group_id(data: &JsonDataMessageV1) -> Option<String> {
+    match (&data.group_v_2, &data.group) {
+        (Some(group), _) => group.id.clone(),
+        (None, Some(group)) => group.group_id.clone(),
+        (None, None) => None,
+    }
+}
+
+fn in_group(mut envelope: Envelope, group: Option<String>) -> Envelope {
+    if let Some(group) = group {
+        envelope.conversation = match GroupId::parse(&group) {
+            Ok(group) => Conversation::Group(group),
+            Err(_) => Conversation::LegacyGroup(group),
+        };
+    }
+    envelope
+}
@@ -0,0 +1,215 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::errors::IdError;
+use crate::types::JsonAddressV1;
+
+/// An E.164 phone number such as `+12024561414`
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct PhoneNumber(String);
+
+impl PhoneNumber {
+    pub fn parse(input: &str) -> Result<Self, IdError> {
+        let valid = match input.strip_prefix('+') {
+            Some(digits) => {
+                (7..=15).contains(&digits.len())
+                    && !digits.starts_with('0')
+                    && digits.bytes().all(|digit| digit.is_ascii_digit())
+            }
+            None => false,
+        };
+
+        if valid {
+            Ok(PhoneNumber(input.to_owned()))
+        } else {
+            Err(IdError::PhoneNumber(input.to_owned()))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The account a request acts as, either its phone number or its ACI uuid
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct AccountId(String);
+
+impl AccountId {
+    pub fn parse(input: &str) -> Result<Self, IdError> {
+        if Uuid::parse_str(input).is_ok() || PhoneNumber::parse(input).is_ok() {
+            Ok(AccountId(input.to_owned()))
+        } else {
+            Err(IdError::Account(input.to_owned()))
+        }
+    }
+
+    /// For accounts signald already accepted, like the ones we are subscribed to
+    pub(crate) fn new_unchecked(account: String) -> Self {
+        AccountId(account)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn phone_number(&self) -> Option<PhoneNumber> {
+        PhoneNumber::parse(&self.0).ok()
+    }
+
+    pub fn uuid(&self) -> Option<Uuid> {
+        Uuid::parse_str(&self.0).ok()
+    }
+}
+
+impl From<PhoneNumber> for AccountId {
+    fn from(number: PhoneNumber) -> Self {
+        AccountId(number.0)
+    }
+}
+
+impl From<Uuid> for AccountId {
+    fn from(uuid: Uuid) -> Self {
+        AccountId(uuid.to_string())
+    }
+}
+
+/// A v2 group id: 32 bytes, base64 encoded
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct GroupId(String);
+
+impl GroupId {
+    pub fn parse(input: &str) -> Result<Self, IdError> {
+        // 32 bytes encode to 43 base64 characters and one padding character
+        let valid = match input.strip_suffix('=') {
+            Some(encoded) => {
+                encoded.len() == 43
+                    && encoded
+                        .bytes()
+                        .all(|c| c.is_ascii_alphanumeric() || c == b'+' || c == b'/')
+            }
+            None => false,
+        };
+
+        if valid {
+            Ok(GroupId(input.to_owned()))
+        } else {
+            Err(IdError::GroupId(input.to_owned()))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A validated Signal address: an ACI or PNI uuid, a phone number, or both
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceAddress {
+    uuid: Option<Uuid>,
+    number: Option<PhoneNumber>,
+}
+
+impl ServiceAddress {
+    pub fn new(uuid: Option<Uuid>, number: Option<PhoneNumber>) -> Result<Self, IdError> {
+        if uuid.is_none() && number.is_none() {
+            return Err(IdError::EmptyAddress);
+        }
+
+        Ok(ServiceAddress { uuid, number })
+    }
+
+    pub fn uuid(&self) -> Option<Uuid> {
+        self.uuid
+    }
+
+    pub fn number(&self) -> Option<&PhoneNumber> {
+        self.number.as_ref()
+    }
+}
+
+impl From<Uuid> for ServiceAddress {
+    fn from(uuid: Uuid) -> Self {
+        ServiceAddress {
+            uuid: Some(uuid),
+            number: None,
+        }
+    }
+}
+
+impl From<PhoneNumber> for ServiceAddress {
+    fn from(number: PhoneNumber) -> Self {
+        ServiceAddress {
+            uuid: None,
+            number: Some(number),
+        }
+    }
+}
+
+impl From<ServiceAddress> for JsonAddressV1 {
+    // Other protocol releases may add fields to addresses
+    #[allow(clippy::needless_update)]
+    fn from(address: ServiceAddress) -> Self {
+        JsonAddressV1 {
+            number: address.number.map(|number| number.0),
+            uuid: address.uuid,
+            relay: None,
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<JsonAddressV1> for ServiceAddress {
+    type Error = IdError;
+
+    fn try_from(address: JsonAddressV1) -> Result<Self, Self::Error> {
+        let number = match address.number.as_deref().map(PhoneNumber::parse).transpose() {
+            Ok(number) => number,
+            // The uuid identifies the address by itself, a number we can't parse is only dropped
+            Err(_) if address.uuid.is_some() => None,
+            Err(e) => return Err(e),
+        };
+        ServiceAddress::new(address.uuid, number)
+    }
+}
+
+macro_rules! string_id {
+    ($name:ident) => {
+        impl FromStr for $name {
+            type Err = IdError;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                $name::parse(input)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let input = String::deserialize(deserializer)?;
+                $name::parse(&input).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+string_id!(PhoneNumber);
+string_id!(AccountId);
+string_id!(GroupId);
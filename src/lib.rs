@@ -1,61 +1,1114 @@
+#![allow(clippy::result_large_err)]
+
+pub mod account;
 pub mod actions;
+pub mod client;
+pub mod compat;
 pub mod errors;
+pub mod event;
+pub mod ids;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+pub mod recipient;
+pub mod runtime;
 pub mod socket;
+pub mod timestamp;
+#[allow(clippy::large_enum_variant, deprecated)]
 pub mod types;
 
+pub use crate::errors::SocketError;
+
 #[cfg(feature = "async-std")]
 pub mod async_std_socket;
-#[cfg(feature = "async-std")]
-pub use crate::async_std_socket::{Signald, SocketError};
 
 #[cfg(feature = "tokio")]
 pub mod tokio_socket;
-#[cfg(feature = "tokio")]
-pub use crate::tokio_socket::{Signald, SocketError};
-#[cfg(feature = "tokio")]
-use tokio::test;
 
 #[cfg(test)]
 mod tests {
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    use super::socket::Timeout;
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
     use super::SocketError;
 
+    /// Round trips generated from the examples in the protocol document
+    mod examples {
+        include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+    }
+
     #[cfg(feature = "tokio")]
     #[tokio::test]
     async fn tokio_register() -> Result<(), SocketError> {
-        register().await
+        let mock = register_mock()?;
+        let (socket, _subscriber) = super::tokio_socket::Signald::connect(mock.path()).await?;
+        register(&socket, &mock).await
     }
 
     #[cfg(feature = "async-std")]
     #[async_std::test]
     async fn async_std_register() -> Result<(), SocketError> {
-        register().await
+        let mock = register_mock()?;
+        let (socket, _subscriber) = super::async_std_socket::Signald::connect(mock.path()).await?;
+        register(&socket, &mock).await
     }
 
-    async fn register() -> Result<(), SocketError> {
-        use super::types::RegisterRequestV1;
-        use super::Signald;
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_listener_survives_bad_input() -> Result<(), SocketError> {
+        use super::actions::VersionError;
+        use super::types::VersionRequestV1;
+        use super::tokio_socket::Signald;
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+        use tokio::net::UnixListener;
 
-        let mut socket = Signald::connect("/tmp/signald.sock", |_| {}).await?;
+        let path = std::env::temp_dir().join(format!("signald-{}.sock", uuid::Uuid::new_v4()));
+        let listener = UnixListener::bind(&path)?;
 
-        let mut register = RegisterRequestV1::default();
-        register.account = Some("+15551234567".to_owned());
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            writer.write_all(b"this is not json\n").await.unwrap();
+            writer.write_all(b"{\"id\":\"not a uuid\"}\n").await.unwrap();
 
-        let response = socket.register(register, None).await;
+            // Hang up once the first request arrives
+            let mut line = String::new();
+            BufReader::new(reader).read_line(&mut line).await.unwrap();
+        });
 
-        match response {
-            Ok(response) => assert_eq!(
-                response.address.unwrap().number.unwrap().as_str(),
-                "+15551234567"
-            ),
-            Err(e) => {
-                if let SocketError::Signald(e) = e {
-                    assert_eq!(e.error_type.as_str(), "CaptchaRequired");
-                } else {
-                    panic!("Received error other than CaptchaRequired");
+        let (socket, mut subscriber) = Signald::connect(&path).await?;
+
+        assert!(matches!(subscriber.recv().await, Some(Err(SocketError::Json(_)))));
+        assert!(matches!(subscriber.recv().await, Some(Err(SocketError::General(_)))));
+
+        let response = socket.version(VersionRequestV1, None, Timeout::Default).await;
+        assert!(matches!(response, Err(VersionError::Socket(SocketError::Closed))));
+
+        server.await.unwrap();
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_request_timeout() -> Result<(), SocketError> {
+        use super::mock::MockSignald;
+        use super::tokio_socket::Signald;
+        use super::actions::VersionError;
+        use super::types::{VersionRequestV1, WaitForScanRequestV1};
+        use std::time::Duration;
+
+        let mock = MockSignald::start()?;
+        mock.ignore("version");
+
+        mock.ignore("wait_for_scan");
+
+        let (socket, _subscriber) = Signald::connect(mock.path()).await?;
+
+        let response = socket
+            .version(VersionRequestV1, None, Timeout::After(Duration::from_millis(50)))
+            .await;
+        assert!(matches!(response, Err(VersionError::Socket(SocketError::Timeout))));
+
+        // The default timeout is shared by every clone
+        socket.clone().socket.set_timeout(Some(Duration::from_millis(50)));
+        let response = socket.version(VersionRequestV1, None, Timeout::Default).await;
+        assert!(matches!(response, Err(VersionError::Socket(SocketError::Timeout))));
+
+        assert_eq!(mock.requests_of("version").len(), 2);
+
+        // Linking waits for the user, not for the default timeout
+        let request = WaitForScanRequestV1::builder().session_id("session").build();
+        let response = tokio::time::timeout(Duration::from_millis(200), socket.wait_for_scan(request, None, Timeout::Default)).await;
+        assert!(response.is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_check_compatibility() -> Result<(), SocketError> {
+        use super::compat::PROTOCOL;
+        use super::mock::MockSignald;
+        use super::tokio_socket::Signald;
+
+        // A daemon that dropped one action, changed a type and added another action
+        let mut protocol: serde_json::Value = serde_json::from_str(PROTOCOL)?;
+        protocol["version"]["version"] = "0.21.0".into();
+        let actions = protocol["actions"]["v1"].as_object_mut().unwrap();
+        actions.remove("wait_for_scan");
+        actions.insert("get_stories".to_owned(), serde_json::json!({"request": "GetStoriesRequest"}));
+        protocol["types"]["v1"]["JsonAddress"]["fields"]["pni"] = serde_json::json!({"type": "String"});
+        // Rewording docs and examples changes nothing on the wire
+        protocol["types"]["v1"]["SendRequest"]["fields"]["messageBody"]["doc"] = "The text".into();
+        protocol["types"]["v1"]["SendRequest"]["fields"]["messageBody"]["example"] = "\"hi\"".into();
+        protocol["types"]["v1"]["SendRequest"]["doc"] = "Send a message".into();
+
+        let mock = MockSignald::start()?;
+        mock.respond("protocol", &protocol);
+
+        let (socket, _subscriber) = Signald::connect(mock.path()).await?;
+        let compatibility = socket.check_compatibility(Timeout::Default).await?;
+
+        assert_eq!(compatibility.daemon_version.as_deref(), Some("0.21.0"));
+        assert_eq!(compatibility.missing_actions, vec!["wait_for_scan"]);
+        assert_eq!(compatibility.new_actions, vec!["get_stories"]);
+        assert_eq!(compatibility.changed_types, vec!["JsonAddressV1"]);
+        assert!(compatibility.changed_actions.is_empty());
+        assert!(!compatibility.is_compatible());
+
+        mock.respond("protocol", serde_json::from_str::<serde_json::Value>(PROTOCOL)?);
+        assert!(socket.check_compatibility(Timeout::Default).await?.is_compatible());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_call_json() -> Result<(), SocketError> {
+        use super::mock::MockSignald;
+        use super::tokio_socket::Signald;
+        use serde_json::json;
+
+        let mock = MockSignald::start()?;
+        mock.respond("get_profile", json!({"name": "Alice", "address": {"number": "+15557654321"}, "pronouns": "she/her"}));
+
+        let (socket, _subscriber) = Signald::connect(mock.path()).await?;
+
+        let payload = json!({"account": "+15551234567", "address": {"number": "+15557654321"}});
+        let response = socket.call_json("get_profile", "v1", payload, None, Timeout::Default).await?;
+        assert_eq!(response["name"], "Alice");
+        // Fields the bundled protocol doesn't know are passed through
+        assert_eq!(response["pronouns"], "she/her");
+        assert_eq!(mock.requests_of("get_profile")[0]["address"]["number"], "+15557654321");
+
+        // Payloads that don't match the request type are never sent
+        let payload = json!({"address": {"number": "+15557654321"}});
+        let response = socket.call_json("get_profile", "v1", payload, None, Timeout::Default).await;
+        assert!(matches!(response, Err(SocketError::Json(_))));
+        let payload = json!({"account": "+15551234567", "address": {"number": "+15557654321"}, "adress": {}});
+        let response = socket.call_json("get_profile", "v1", payload, None, Timeout::Default).await;
+        assert!(matches!(response, Err(SocketError::Json(e)) if e.to_string().contains("unknown field `adress`")));
+        let payload = json!({"account": "+15551234567", "address": {"numbr": "+15557654321"}});
+        let response = socket.call_json("get_profile", "v1", payload, None, Timeout::Default).await;
+        assert!(matches!(response, Err(SocketError::Json(e)) if e.to_string().contains("unknown field `numbr` in JsonAddress")));
+        assert_eq!(mock.requests_of("get_profile").len(), 1);
+
+        let response = socket.call_json("get_profile", "v0", json!({}), None, Timeout::Default).await;
+        assert!(matches!(response, Err(SocketError::General(_))));
+        let response = socket.call_json("no_such_action", "v1", json!({}), None, Timeout::Default).await;
+        assert!(matches!(response, Err(SocketError::General(_))));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_address_book() -> Result<(), SocketError> {
+        use super::ids::{AccountId, PhoneNumber};
+        use super::mock::MockSignald;
+        use super::recipient::{AddressBook, Recipient};
+        use super::tokio_socket::Signald;
+        use super::types::JsonAddressV1;
+        use serde_json::json;
+
+        let uuid = uuid::Uuid::parse_str("0cc10e61-d64c-4dbc-b51c-334f7dd45a4a").unwrap();
+        let number = PhoneNumber::parse("+15557654321").unwrap();
+        let by_number = JsonAddressV1 {
+            number: Some(number.to_string()),
+            ..Default::default()
+        };
+        let by_uuid = JsonAddressV1 {
+            uuid: Some(uuid),
+            ..Default::default()
+        };
+
+        let mut book = AddressBook::new();
+        assert_eq!(book.recipient(&by_number).unwrap(), Recipient::Number(number.clone()));
+        assert!(!book.same(&by_number, &by_uuid));
+
+        // An invalid number next to a uuid is left out of the book
+        let invalid_number = JsonAddressV1 {
+            number: Some(String::from("5557654321")),
+            ..by_uuid.clone()
+        };
+        assert_eq!(book.insert(&invalid_number).unwrap(), Recipient::Uuid(uuid));
+        assert!(!book.same(&by_number, &by_uuid));
+
+        let mock = MockSignald::start()?;
+        mock.respond("resolve_address", json!({"number": "+15557654321", "uuid": uuid}));
+        let (socket, _subscriber) = Signald::connect(mock.path()).await?;
+
+        let account = AccountId::parse("+15551234567").unwrap();
+        let resolved = book.resolve(&socket, account.clone(), &Recipient::from(number.clone())).await?;
+        assert_eq!(resolved, Recipient::Uuid(uuid));
+        assert!(book.same(&by_number, &by_uuid));
+        assert_eq!(book.address(&resolved).number.as_deref(), Some("+15557654321"));
+
+        // Known recipients are answered from the book
+        book.resolve(&socket, account, &Recipient::from(number)).await?;
+        assert_eq!(mock.requests_of("resolve_address").len(), 1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_account_handle() -> Result<(), SocketError> {
+        use super::actions::ReactError;
+        use super::ids::AccountId;
+        use super::mock::MockSignald;
+        use super::tokio_socket::Signald;
+        use super::types::{JsonReactionV1, ReactRequestV1, SendRequestV1};
+        use serde_json::json;
+
+        let mock = MockSignald::start()?;
+        mock.respond("send", json!({"timestamp": 1615576442475_i64}));
+        mock.respond("react", json!({"timestamp": 1615576442475_i64}));
+
+        let (socket, _subscriber) = Signald::connect(mock.path()).await?;
+        let alice = socket.account("+15551234567").unwrap();
+        assert!(socket.account("alice").is_err());
+
+        let message = alice.send_request().message_body("hello").build();
+        alice.send(message, None, Timeout::Default).await?;
+        assert_eq!(mock.requests_of("send")[0]["account"], "+15551234567");
+
+        // An optional account left unset is filled in
+        let message = SendRequestV1::builder().message_body("hello").build();
+        alice.send(message, None, Timeout::Default).await?;
+        assert_eq!(mock.requests_of("send")[1]["account"], "+15551234567");
+
+        let reaction = alice.react_request(JsonReactionV1::default()).build();
+        alice.react(reaction, None, Timeout::Default).await?;
+        assert_eq!(mock.requests_of("react")[0]["username"], "+15551234567");
+
+        // Requests for another account never reach signald
+        let reaction = ReactRequestV1::builder(
+            JsonReactionV1::default(),
+            AccountId::parse("+15557654321").unwrap(),
+        )
+        .build();
+        let response = alice.react(reaction, None, Timeout::Default).await;
+        assert!(matches!(response, Err(ReactError::Socket(SocketError::General(_)))));
+        assert_eq!(mock.requests_of("react").len(), 1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_account_events() -> Result<(), SocketError> {
+        use super::mock::MockSignald;
+        use super::tokio_socket::Signald;
+        use super::types::{ClientEvent, IncomingMessageV1};
+        use std::time::Duration;
+
+        let mock = MockSignald::start()?;
+        let (socket, mut all) = Signald::connect(mock.path()).await?;
+
+        let alice = socket.account("+15551234567").unwrap();
+        let mut alice_events = alice.subscribe_events(true).await?;
+        let mut bob_events = socket.socket.account_events(&"+15557654321".parse().unwrap());
+        assert_eq!(mock.requests_of("subscribe").len(), 1);
+
+        mock.push_incoming_message("+15557654321", IncomingMessageV1::default());
+        mock.push_incoming_message("+15551234567", IncomingMessageV1::default());
+
+        let account = |event: Option<Result<ClientEvent, SocketError>>| match event {
+            Some(Ok(event)) => event.account().map(str::to_owned),
+            e => panic!("Received unexpected event {:?}", e),
+        };
+        assert_eq!(account(all.recv().await).as_deref(), Some("+15557654321"));
+        assert_eq!(account(all.recv().await).as_deref(), Some("+15551234567"));
+        assert_eq!(account(bob_events.recv().await).as_deref(), Some("+15557654321"));
+        assert_eq!(account(alice_events.recv().await).as_deref(), Some("+15551234567"));
+
+        // A stream opened right after the last one was dropped keeps alice subscribed
+        drop(alice_events);
+        let mut alice_events = alice.subscribe_events(true).await?;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(mock.requests_of("unsubscribe").is_empty());
+        assert_eq!(socket.socket.subscriptions(), vec!["+15551234567"]);
+        mock.push_incoming_message("+15551234567", IncomingMessageV1::default());
+        assert_eq!(account(alice_events.recv().await).as_deref(), Some("+15551234567"));
+
+        // Dropping alice's only stream unsubscribes her
+        drop(alice_events);
+        for _ in 0..100 {
+            if socket.socket.subscriptions().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(mock.requests_of("unsubscribe")[0]["account"], "+15551234567");
+        assert!(socket.socket.subscriptions().is_empty());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn tokio_stream_dropped_outside_runtime() -> Result<(), SocketError> {
+        use super::mock::MockSignald;
+        use super::tokio_socket::Signald;
+        use std::time::Duration;
+
+        let runtime = tokio::runtime::Runtime::new()?;
+        let mock = MockSignald::start()?;
+        let (socket, events) = runtime.block_on(async {
+            let (socket, _subscriber) = Signald::connect(mock.path()).await?;
+            let events = socket.account("+15551234567").unwrap().subscribe_events(true).await?;
+            Ok::<_, SocketError>((socket, events))
+        })?;
+
+        drop(events);
+        runtime.block_on(async {
+            for _ in 0..100 {
+                if socket.socket.subscriptions().is_empty() {
+                    break;
                 }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        });
+        assert_eq!(mock.requests_of("unsubscribe")[0]["account"], "+15551234567");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_incoming_message() -> Result<(), SocketError> {
+        use super::mock::MockSignald;
+        use super::tokio_socket::Signald;
+        use super::timestamp::Timestamp;
+        use super::types::{ClientEvent, IncomingMessageV1};
+
+        let mock = MockSignald::start()?;
+        let (_socket, mut subscriber) = Signald::connect(mock.path()).await?;
+
+        let message = IncomingMessageV1 {
+            timestamp: Some(Timestamp(1615576442475)),
+            ..Default::default()
+        };
+        mock.push_incoming_message("+15551234567", message);
+
+        match subscriber.recv().await {
+            Some(Ok(ClientEvent::IncomingMessage { account, data })) => {
+                assert_eq!(account.unwrap(), "+15551234567");
+                assert_eq!(data.timestamp, Some(Timestamp(1615576442475)));
             }
+            e => panic!("Received unexpected event {:?}", e),
         }
 
         Ok(())
     }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_concurrent_requests() -> Result<(), SocketError> {
+        use super::types::VersionRequestV1;
+        use super::tokio_socket::Signald;
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+        use tokio::net::UnixListener;
+
+        let path = std::env::temp_dir().join(format!("signald-{}.sock", uuid::Uuid::new_v4()));
+        let listener = UnixListener::bind(&path)?;
+
+        // Collect a batch of requests, then answer them in reverse order
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+
+            let mut ids = Vec::new();
+            while ids.len() < 8 {
+                let line = lines.next_line().await.unwrap().unwrap();
+                let request: serde_json::Value = serde_json::from_str(&line).unwrap();
+                ids.push(request["id"].as_str().unwrap().to_owned());
+            }
+
+            for id in ids.iter().rev() {
+                let response = serde_json::json!({
+                    "id": id,
+                    "type": "version",
+                    "data": {"name": "signald", "version": id}
+                });
+                writer.write_all(format!("{}\n", response).as_bytes()).await.unwrap();
+            }
+        });
+
+        let (socket, _subscriber) = Signald::connect(&path).await?;
+
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let socket = socket.clone();
+                tokio::spawn(async move {
+                    let id = uuid::Uuid::new_v4();
+                    let response = socket.version(VersionRequestV1, Some(id), Timeout::Default).await?;
+                    assert_eq!(response.version.unwrap(), id.to_simple().to_string());
+                    Ok::<_, SocketError>(())
+                })
+            })
+            .collect();
+
+        for task in tasks {
+            task.await.unwrap()?;
+        }
+
+        server.await.unwrap();
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_reconnect_restores_subscriptions() -> Result<(), SocketError> {
+        use super::socket::{ConnectionState, Reconnect};
+        use super::types::{ClientEvent, SubscribeRequestV1, VersionRequestV1};
+        use super::tokio_socket::Signald;
+        use std::time::Duration;
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+        use tokio::net::UnixListener;
+
+        let path = std::env::temp_dir().join(format!("signald-{}.sock", uuid::Uuid::new_v4()));
+        let listener = UnixListener::bind(&path)?;
+
+        // Answer requests in order, returning the types of the requests answered
+        async fn serve(stream: tokio::net::UnixStream, requests: usize) -> Vec<String> {
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            let mut types = Vec::new();
+
+            while types.len() < requests {
+                let line = lines.next_line().await.unwrap().unwrap();
+                let request: serde_json::Value = serde_json::from_str(&line).unwrap();
+                let response = serde_json::json!({
+                    "id": request["id"],
+                    "type": request["type"],
+                    "data": {}
+                });
+                writer.write_all(format!("{}\n", response).as_bytes()).await.unwrap();
+                types.push(request["type"].as_str().unwrap().to_owned());
+            }
+            types
+        }
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            assert_eq!(serve(stream, 1).await, vec!["subscribe"]);
+
+            let (stream, _) = listener.accept().await.unwrap();
+            assert_eq!(serve(stream, 2).await, vec!["subscribe", "version"]);
+        });
+
+        let reconnect = Reconnect {
+            initial_backoff: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let (socket, mut subscriber) = Signald::connect_with_reconnect(&path, reconnect).await?;
+
+        let request = SubscribeRequestV1 {
+            account: "+15551234567".parse().unwrap(),
+        };
+        socket.subscribe(request, None, Timeout::Default).await?;
+        assert_eq!(socket.socket.subscriptions(), vec!["+15551234567"]);
+
+        let mut states = Vec::new();
+        while let Some(event) = subscriber.recv().await {
+            if let Ok(ClientEvent::Connection { state }) = event {
+                states.push(state);
+                if state == ConnectionState::Connected {
+                    break;
+                }
+            }
+        }
+        assert_eq!(
+            states,
+            vec![
+                ConnectionState::Disconnected,
+                ConnectionState::Reconnecting { attempt: 1 },
+                ConnectionState::Connected
+            ]
+        );
+
+        socket.version(VersionRequestV1, None, Timeout::Default).await?;
+
+        server.await.unwrap();
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_reconnect_failures_are_reported() -> Result<(), SocketError> {
+        use super::mock::MockSignald;
+        use super::socket::Reconnect;
+        use super::tokio_socket::Signald;
+        use super::types::ClientEvent;
+        use std::time::Duration;
+
+        let mock = MockSignald::start()?;
+        let reconnect = Reconnect {
+            initial_backoff: Duration::from_millis(10),
+            max_attempts: Some(2),
+            ..Default::default()
+        };
+        let (_socket, mut subscriber) = Signald::connect_with_reconnect(mock.path(), reconnect).await?;
+
+        // Removes the socket, so every attempt fails
+        drop(mock);
+
+        let mut events = Vec::new();
+        while let Some(event) = subscriber.recv().await {
+            events.push(match event {
+                Ok(ClientEvent::Connection { state }) => format!("{:?}", state),
+                Err(SocketError::Io(_)) => "Io".to_owned(),
+                other => panic!("unexpected event {:?}", other),
+            });
+        }
+        assert_eq!(
+            events,
+            vec![
+                "Disconnected",
+                "Reconnecting { attempt: 1 }",
+                "Io",
+                "Reconnecting { attempt: 2 }",
+                "Io",
+                "Closed"
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn tokio_replay_during_reconnect() -> Result<(), SocketError> {
+        use super::mock::MockSignald;
+        use super::socket::{ConnectionState, Reconnect};
+        use super::tokio_socket::Signald;
+        use super::types::{ClientEvent, VersionRequestV1};
+        use std::time::Duration;
+
+        let mock = MockSignald::start()?;
+        mock.ignore("version");
+
+        let reconnect = Reconnect {
+            initial_backoff: Duration::from_millis(20),
+            replay_pending: true,
+            ..Default::default()
+        };
+        let (socket, mut subscriber) = Signald::connect_with_reconnect(mock.path(), reconnect).await?;
+        let version = |socket: &Signald| {
+            let socket = socket.clone();
+            tokio::spawn(async move { socket.version(VersionRequestV1, None, Timeout::Default).await })
+        };
+
+        // In flight when signald goes away
+        let mut requests = vec![version(&socket)];
+        while mock.requests_of("version").is_empty() {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        mock.respond("version", serde_json::json!({"version": "0.23.0"}));
+        mock.disconnect();
+
+        // Keep issuing requests from several tasks until the connection is back, so
+        // some of them wait for the writer while the supervisor replays
+        let reconnected = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let spammers: Vec<_> = (0..4)
+            .map(|_| {
+                let socket = socket.clone();
+                let reconnected = reconnected.clone();
+                tokio::spawn(async move {
+                    let mut requests = Vec::new();
+                    while !reconnected.load(std::sync::atomic::Ordering::SeqCst) {
+                        requests.push(version(&socket));
+                        tokio::task::yield_now().await;
+                    }
+                    requests
+                })
+            })
+            .collect();
+        while let Some(event) = subscriber.recv().await {
+            if let Ok(ClientEvent::Connection { state: ConnectionState::Connected }) = event {
+                break;
+            }
+        }
+        reconnected.store(true, std::sync::atomic::Ordering::SeqCst);
+        for spammer in spammers {
+            requests.extend(spammer.await.unwrap());
+        }
+        for request in requests {
+            request.await.unwrap()?;
+        }
+
+        // Only the request signald saw before it went away is sent twice
+        let ids: Vec<serde_json::Value> = mock.requests_of("version").into_iter().map(|r| r["id"].clone()).collect();
+        for (i, id) in ids.iter().enumerate() {
+            let expected = if *id == ids[0] { 2 } else { 1 };
+            assert_eq!(ids.iter().filter(|other| *other == id).count(), expected, "request {}", i);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn tokio_shutdown() -> Result<(), SocketError> {
+        use super::socket::ConnectionState;
+        use super::actions::VersionError;
+        use super::types::{ClientEvent, SubscribeRequestV1, VersionRequestV1};
+        use super::tokio_socket::Signald;
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+        use tokio::net::UnixListener;
+
+        let path = std::env::temp_dir().join(format!("signald-{}.sock", uuid::Uuid::new_v4()));
+        let listener = UnixListener::bind(&path)?;
+
+        // Answer everything but version requests, reporting the request types seen
+        let (types_tx, mut types) = tokio::sync::mpsc::unbounded_channel();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+
+            while let Ok(Some(line)) = lines.next_line().await {
+                let request: serde_json::Value = serde_json::from_str(&line).unwrap();
+                types_tx.send(request["type"].as_str().unwrap().to_owned()).unwrap();
+                if request["type"] != "version" {
+                    let response = serde_json::json!({"id": request["id"], "type": request["type"]});
+                    writer.write_all(format!("{}\n", response).as_bytes()).await.unwrap();
+                }
+            }
+        });
+
+        let (socket, mut subscriber) = Signald::connect(&path).await?;
+
+        let request = SubscribeRequestV1 {
+            account: "+15551234567".parse().unwrap(),
+        };
+        socket.subscribe(request, None, Timeout::Default).await?;
+        assert_eq!(types.recv().await.unwrap(), "subscribe");
+
+        let pending = {
+            let socket = socket.clone();
+            tokio::spawn(async move { socket.version(VersionRequestV1, None, Timeout::Default).await })
+        };
+        assert_eq!(types.recv().await.unwrap(), "version");
+
+        socket.shutdown(true).await?;
+        assert!(socket.socket.subscriptions().is_empty());
+        assert!(matches!(pending.await.unwrap(), Err(VersionError::Socket(SocketError::Closed))));
+        assert!(matches!(
+            socket.version(VersionRequestV1, None, Timeout::Default).await,
+            Err(VersionError::Socket(SocketError::Closed))
+        ));
+
+        // The subscriber ends after reporting the connection closed
+        let mut last = None;
+        while let Some(event) = subscriber.recv().await {
+            last = Some(event);
+        }
+        assert!(matches!(
+            last,
+            Some(Ok(ClientEvent::Connection {
+                state: ConnectionState::Closed
+            }))
+        ));
+
+        assert_eq!(types.recv().await.unwrap(), "unsubscribe");
+        server.await.unwrap();
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "async-std")]
+    #[async_std::test]
+    async fn async_std_shutdown() -> Result<(), SocketError> {
+        use super::async_std_socket::Signald;
+        use super::mock::MockSignald;
+        use super::socket::ConnectionState;
+        use super::actions::VersionError;
+        use super::types::{ClientEvent, VersionRequestV1};
+
+        let mock = MockSignald::start()?;
+        mock.ignore("version");
+
+        let (socket, mut subscriber) = Signald::connect(mock.path()).await?;
+
+        let pending = {
+            let socket = socket.clone();
+            async_std::task::spawn(async move { socket.version(VersionRequestV1, None, Timeout::Default).await })
+        };
+        async_std::task::sleep(std::time::Duration::from_millis(10)).await;
+
+        socket.shutdown(false).await?;
+        assert!(matches!(pending.await, Err(VersionError::Socket(SocketError::Closed))));
+
+        let mut last = None;
+        while let Some(event) = subscriber.recv().await {
+            last = Some(event);
+        }
+        assert!(matches!(
+            last,
+            Some(Ok(ClientEvent::Connection {
+                state: ConnectionState::Closed
+            }))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn error_kind_from_error() {
+        use super::errors::{SignaldError, SignaldErrorKind};
+
+        let error: SignaldError = serde_json::from_str(
+            r#"{"id":"1","type":"register","error_type":"CaptchaRequiredError","error":{"message":"a captcha token is required to register","more":"https://signald.org/articles/captcha/"}}"#,
+        )
+        .unwrap();
+
+        match SignaldErrorKind::from(error.clone()) {
+            SignaldErrorKind::CaptchaRequired(e) => {
+                assert_eq!(e.more.unwrap(), "https://signald.org/articles/captcha/")
+            }
+            e => panic!("Parsed unexpected error kind {:?}", e),
+        }
+
+        let error = SignaldError {
+            error_type: "SomeFutureError".to_owned(),
+            ..error
+        };
+        let kind = SignaldErrorKind::from(error);
+        assert_eq!(kind.error_type(), "SomeFutureError");
+        assert_eq!(kind.message(), Some("a captcha token is required to register"));
+    }
+
+    #[test]
+    fn deprecation_hook() {
+        use super::socket::{report_deprecated, set_deprecation_hook};
+        use std::sync::{Arc, Mutex};
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        {
+            let reported = reported.clone();
+            // Hooks may replace themselves
+            set_deprecation_hook(move |deprecation| {
+                reported.lock().unwrap().push((deprecation.action, deprecation.removal_date));
+                set_deprecation_hook(|_| {});
+            });
+        }
+
+        report_deprecated("version", Some("2022-01-01"));
+        report_deprecated("version", None);
+        assert_eq!(*reported.lock().unwrap(), vec![("version", Some("2022-01-01"))]);
+    }
+
+    /// The bundled protocol deprecates no actions, tests/protocol_override.rs runs this
+    /// against one that deprecates `get_servers`
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn tokio_deprecated_action_is_reported() -> Result<(), SocketError> {
+        use super::actions::action_info;
+        use super::mock::MockSignald;
+        use super::socket::set_deprecation_hook;
+        use super::tokio_socket::Signald;
+        use super::types::GetServersRequestV1;
+        use std::sync::{Arc, Mutex};
+
+        if !action_info("get_servers").is_some_and(|action| action.deprecated) {
+            return Ok(());
+        }
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        {
+            let reported = reported.clone();
+            set_deprecation_hook(move |deprecation| {
+                reported.lock().unwrap().push((deprecation.action, deprecation.removal_date));
+            });
+        }
+
+        let mock = MockSignald::start()?;
+        let (socket, _subscriber) = Signald::connect(mock.path()).await?;
+        socket.get_servers(GetServersRequestV1, None, Timeout::Default).await?;
+
+        assert_eq!(*reported.lock().unwrap(), vec![("get_servers", Some("2022-01-01"))]);
+        Ok(())
+    }
+
+    #[test]
+    fn typed_fields() {
+        use super::timestamp::Timestamp;
+        use super::types::{ClientMessageWrapperV1, JsonAddressV1, JsonQuoteV1, JsonReactionV1};
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let address: JsonAddressV1 = serde_json::from_value(serde_json::json!({
+            "number": "+15551234567",
+            "uuid": "aeed01f0-a234-478e-8cf7-261c283151e7"
+        }))
+        .unwrap();
+        assert_eq!(address.uuid.unwrap().to_string(), "aeed01f0-a234-478e-8cf7-261c283151e7");
+
+        let wrapper: ClientMessageWrapperV1 = serde_json::from_value(serde_json::json!({
+            "type": "IncomingMessage",
+            "data": {"data_message": {"timestamp": 1615576442475i64}}
+        }))
+        .unwrap();
+        assert_eq!(wrapper.data.unwrap()["data_message"]["timestamp"], 1615576442475i64);
+
+        let timestamp = Timestamp(1615576442475);
+        assert_eq!(serde_json::to_value(timestamp).unwrap(), 1615576442475i64);
+        let time: std::time::SystemTime = timestamp.into();
+        assert_eq!(time, UNIX_EPOCH + Duration::from_millis(1615576442475));
+        assert_eq!(Timestamp::from(time), timestamp);
+
+        // Quotes name their message by its timestamp, like reactions do
+        let quote: JsonQuoteV1 = serde_json::from_value(serde_json::json!({"id": 1615576442475i64})).unwrap();
+        let reaction: JsonReactionV1 = serde_json::from_value(serde_json::json!({"targetSentTimestamp": 1615576442475i64})).unwrap();
+        assert_eq!(quote.id, reaction.target_sent_timestamp);
+    }
+
+    #[test]
+    fn string_enums() {
+        use super::ids::AccountId;
+        use super::types::{GroupMemberV1, JsonAddressV1, MemberRole, TrustLevel, TrustRequestV1};
+
+        let member: GroupMemberV1 = serde_json::from_value(serde_json::json!({"role": "ADMINISTRATOR"})).unwrap();
+        assert_eq!(member.role, Some(MemberRole::Administrator));
+
+        // Values this crate doesn't know about survive a round trip
+        let member: GroupMemberV1 = serde_json::from_value(serde_json::json!({"role": "MODERATOR"})).unwrap();
+        assert_eq!(member.role, Some(MemberRole::Unknown("MODERATOR".to_owned())));
+        assert_eq!(serde_json::to_value(&member).unwrap()["role"], "MODERATOR");
+
+        let request = TrustRequestV1::builder(AccountId::parse("+15551234567").unwrap(), JsonAddressV1::default())
+            .trust_level(TrustLevel::TrustedVerified)
+            .build();
+        assert_eq!(serde_json::to_value(&request).unwrap()["trust_level"], "TRUSTED_VERIFIED");
+    }
+
+    #[test]
+    fn action_registry() {
+        use super::actions::{action_info, ACTIONS};
+
+        assert!(ACTIONS.windows(2).all(|pair| pair[0].name < pair[1].name));
+
+        let send = action_info("send").unwrap();
+        assert_eq!(send.version, "v1");
+        assert_eq!(send.request, "SendRequestV1");
+        assert_eq!(send.response, Some("SendResponseV1"));
+        assert!(send.errors.contains(&"RateLimitError"));
+        assert!(!send.deprecated);
+
+        assert!(action_info("no_such_action").is_none());
+    }
+
+    #[test]
+    fn identifiers() {
+        use super::errors::IdError;
+        use super::ids::{AccountId, GroupId, PhoneNumber, ServiceAddress};
+        use super::types::{JsonAddressV1, SubscribeRequestV1};
+        use std::convert::TryFrom;
+
+        assert!(PhoneNumber::parse("+12024561414").is_ok());
+        assert!(PhoneNumber::parse("12024561414").is_err());
+        assert!(PhoneNumber::parse("+1202 456").is_err());
+
+        let account = AccountId::parse("0cc10e61-d64c-4dbc-b51c-334f7dd45a4a").unwrap();
+        assert!(account.uuid().is_some() && account.phone_number().is_none());
+        assert!(matches!(AccountId::parse("alice"), Err(IdError::Account(_))));
+
+        assert!(GroupId::parse("EdSqI90cS0UomDpgUXOlCoObWvQOXlH5G3Z2d3f4ayE=").is_ok());
+        assert!(GroupId::parse("EdSqI90cS0UomDpgUXOlCoObWvQ=").is_err());
+
+        // Invalid identifiers are rejected while parsing a request, before anything is sent
+        let request = serde_json::from_value::<SubscribeRequestV1>(serde_json::json!({"account": "alice"}));
+        assert!(request.is_err());
+
+        let address = ServiceAddress::from(PhoneNumber::parse("+12024561414").unwrap());
+        let json = JsonAddressV1::from(address.clone());
+        assert_eq!(json.number.as_deref(), Some("+12024561414"));
+        assert_eq!(ServiceAddress::try_from(json).unwrap(), address);
+        assert_eq!(ServiceAddress::try_from(JsonAddressV1::default()), Err(IdError::EmptyAddress));
+
+        // An invalid number only fails addresses without a uuid
+        let uuid = uuid::Uuid::parse_str("0cc10e61-d64c-4dbc-b51c-334f7dd45a4a").unwrap();
+        let json = JsonAddressV1 {
+            number: Some(String::from("2024561414")),
+            uuid: Some(uuid),
+            ..Default::default()
+        };
+        assert_eq!(ServiceAddress::try_from(json.clone()).unwrap(), ServiceAddress::from(uuid));
+        let json = JsonAddressV1 { uuid: None, ..json };
+        assert!(matches!(ServiceAddress::try_from(json), Err(IdError::PhoneNumber(_))));
+    }
+
+    #[test]
+    fn client_event_from_value() {
+        use super::errors::SignaldErrorKind;
+        use super::timestamp::Timestamp;
+        use super::types::ClientEvent;
+
+        let event = ClientEvent::from_value(serde_json::json!({
+            "type": "ListenerState",
+            "version": "v1",
+            "data": {"connected": false},
+            "account": "+15551234567"
+        }))
+        .unwrap();
+
+        assert_eq!(event.account(), Some("+15551234567"));
+        match event {
+            ClientEvent::ListenerState { data, .. } => assert_eq!(data.connected, Some(false)),
+            e => panic!("Parsed unexpected event {:?}", e),
+        }
+
+        let event = ClientEvent::from_value(serde_json::json!({
+            "type": "DuplicateMessageError",
+            "version": "v1",
+            "data": {"timestamp": 1615576442475i64, "message": "duplicate message"},
+            "error": true,
+            "account": "+15551234567"
+        }))
+        .unwrap();
+
+        match event {
+            ClientEvent::Error {
+                error: SignaldErrorKind::DuplicateMessage(e),
+                ..
+            } => assert_eq!(e.timestamp, Some(Timestamp(1615576442475))),
+            e => panic!("Parsed unexpected event {:?}", e),
+        }
+    }
+
+    #[test]
+    fn normalized_events() {
+        use super::event::{Conversation, Event};
+        use super::ids::{GroupId, PhoneNumber};
+        use super::recipient::Recipient;
+        use super::timestamp::Timestamp;
+        use super::types::{IncomingMessageV1, ReceiptType};
+        use serde_json::json;
+
+        let event = |message: serde_json::Value| {
+            let mut message: IncomingMessageV1 = serde_json::from_value(message).unwrap();
+            message.source = serde_json::from_value(json!({"number": "+15557654321"})).unwrap();
+            message.timestamp = Some(Timestamp(1615576442475));
+            Event::from_incoming(message)
+        };
+        let bob = Recipient::Number(PhoneNumber::parse("+15557654321").unwrap());
+        let group = "EdSqI90cS0UomDpgUXOlCoObWvQOXlH5G3Z2d3f4ayE=";
+
+        match event(json!({"data_message": {"body": "hello", "groupV2": {"id": group}}})).unwrap() {
+            Event::Text { envelope, body, .. } => {
+                assert_eq!(body.as_deref(), Some("hello"));
+                assert_eq!(envelope.sender, bob);
+                assert_eq!(envelope.conversation, Conversation::Group(GroupId::parse(group).unwrap()));
+                assert_eq!(envelope.timestamp, Timestamp(1615576442475));
+            }
+            e => panic!("Sorted unexpected event {:?}", e),
+        }
+
+        // Legacy groups have shorter ids
+        match event(json!({"data_message": {"body": "hello", "group": {"groupId": "VGhpcyBpcyBsZWdhY3k="}}})).unwrap() {
+            Event::Text { envelope, .. } => {
+                assert_eq!(envelope.conversation, Conversation::LegacyGroup("VGhpcyBpcyBsZWdhY3k=".to_owned()));
+            }
+            e => panic!("Sorted unexpected event {:?}", e),
+        }
+
+        let reaction = json!({"emoji": "👍", "targetAuthor": {"number": "+15551234567"}, "targetSentTimestamp": 1});
+        match event(json!({"data_message": {"reaction": reaction}})).unwrap() {
+            Event::Reaction { emoji, target_timestamp, .. } => {
+                assert_eq!(emoji, "👍");
+                assert_eq!(target_timestamp, Timestamp(1));
+            }
+            e => panic!("Sorted unexpected event {:?}", e),
+        }
+
+        match event(json!({"receipt_message": {"type": "READ", "timestamps": [1, 2]}})).unwrap() {
+            Event::Receipt { kind, timestamps, envelope } => {
+                assert_eq!(kind, ReceiptType::Read);
+                assert_eq!(timestamps, vec![Timestamp(1), Timestamp(2)]);
+                assert_eq!(envelope.conversation, Conversation::Direct(bob.clone()));
+            }
+            e => panic!("Sorted unexpected event {:?}", e),
+        }
+
+        match event(json!({"receipt_message": {"timestamps": [1]}})).unwrap() {
+            Event::Receipt { kind, .. } => assert_eq!(kind, ReceiptType::Unknown("UNKNOWN".to_owned())),
+            e => panic!("Sorted unexpected event {:?}", e),
+        }
+
+        // Messages sent from another device belong to the conversation with their recipient
+        let sent = json!({"destination": {"number": "+15551234567"}, "message": {"body": "hi"}});
+        match event(json!({"sync_message": {"sent": sent}})).unwrap() {
+            Event::SyncSent { envelope, body, .. } => {
+                assert_eq!(body.as_deref(), Some("hi"));
+                assert_eq!(
+                    envelope.conversation,
+                    Conversation::Direct(Recipient::Number(PhoneNumber::parse("+15551234567").unwrap()))
+                );
+            }
+            e => panic!("Sorted unexpected event {:?}", e),
+        }
+
+        let end_session = event(json!({"data_message": {"endSession": true}})).unwrap();
+        assert!(matches!(end_session, Event::Other { .. }));
+        assert_eq!(end_session.sender(), &bob);
+
+        assert!(Event::from_incoming(IncomingMessageV1::default()).is_none());
+
+        // A sender with a uuid is known even when signald reports a number we can't parse
+        let uuid = uuid::Uuid::parse_str("0cc10e61-d64c-4dbc-b51c-334f7dd45a4a").unwrap();
+        let mut message: IncomingMessageV1 = serde_json::from_value(json!({"data_message": {"body": "hello"}})).unwrap();
+        message.source = serde_json::from_value(json!({"number": "5557654321", "uuid": uuid})).unwrap();
+        message.timestamp = Some(Timestamp(1615576442475));
+        assert_eq!(Event::from_incoming(message).unwrap().sender(), &Recipient::Uuid(uuid));
+    }
+
+    /// A signald that wants a captcha before registering
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    fn register_mock() -> std::io::Result<super::mock::MockSignald> {
+        let mock = super::mock::MockSignald::start()?;
+        mock.respond_error(
+            "register",
+            "CaptchaRequiredError",
+            serde_json::json!({
+                "message": "a captcha token is required to register",
+                "more": "https://signald.org/articles/captcha/"
+            }),
+        );
+        Ok(mock)
+    }
+
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    async fn register<T: super::socket::AsyncSocket>(
+        socket: &super::actions::SocketWrapper<T>,
+        mock: &super::mock::MockSignald,
+    ) -> Result<(), SocketError> {
+        use super::actions::RegisterError;
+        use super::ids::AccountId;
+        use super::types::RegisterRequestV1;
+
+        let account = AccountId::parse("+15551234567").unwrap();
+        let register = RegisterRequestV1::builder(account).voice(true).build();
+
+        let response = socket.register(register, None, Timeout::Default).await;
+
+        match response {
+            Err(RegisterError::CaptchaRequired(e)) => {
+                assert_eq!(e.more.unwrap(), "https://signald.org/articles/captcha/")
+            }
+            r => panic!("Expected CaptchaRequired, received {:?}", r.map(|_| ())),
+        }
+
+        let requests = mock.requests_of("register");
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["account"], "+15551234567");
+        assert_eq!(requests[0]["voice"], true);
+        assert!(requests[0].get("captcha").is_none());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,217 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Result, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use uuid::Uuid;
+
+use crate::types::IncomingMessageV1;
+
+/// How the mock answers a request type
+#[derive(Clone, Debug)]
+enum Response {
+    Data(Value),
+    Error { error_type: String, error: Value },
+    Ignore,
+}
+
+#[derive(Default)]
+struct State {
+    responses: HashMap<String, Response>,
+    requests: Vec<Value>,
+    clients: Vec<UnixStream>,
+    /// Events pushed while no client was connected, sent to the next one
+    queued: Vec<Value>,
+}
+
+/// An in-process signald speaking the line-delimited JSON protocol on a
+/// temporary Unix socket.
+///
+/// Every request is recorded and answered with an empty `data` object unless
+/// a response was scripted for its type. The server runs on its own threads,
+/// so it works the same under either runtime. The socket is removed on drop.
+pub struct MockSignald {
+    path: PathBuf,
+    state: Arc<Mutex<State>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl MockSignald {
+    /// Bind a new mock on a fresh socket in the temporary directory
+    pub fn start() -> Result<MockSignald> {
+        let path = std::env::temp_dir().join(format!("signald-mock-{}.sock", Uuid::new_v4()));
+        let listener = UnixListener::bind(&path)?;
+
+        let state = Arc::new(Mutex::new(State::default()));
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        {
+            let state = state.clone();
+            let stopped = stopped.clone();
+            thread::spawn(move || accept(listener, state, stopped));
+        }
+
+        Ok(MockSignald { path, state, stopped })
+    }
+
+    /// Path of the socket to connect the client to
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Answer every request of type `type_` with `data`
+    pub fn respond<T: Serialize>(&self, type_: &str, data: T) {
+        let data = serde_json::to_value(data).unwrap_or(Value::Null);
+        self.script(type_, Response::Data(data));
+    }
+
+    /// Answer every request of type `type_` with a signald error such as `"CaptchaRequiredError"`
+    pub fn respond_error<T: Serialize>(&self, type_: &str, error_type: &str, error: T) {
+        let error = serde_json::to_value(error).unwrap_or(Value::Null);
+        self.script(
+            type_,
+            Response::Error {
+                error_type: error_type.to_owned(),
+                error,
+            },
+        );
+    }
+
+    /// Never answer requests of type `type_`
+    pub fn ignore(&self, type_: &str) {
+        self.script(type_, Response::Ignore);
+    }
+
+    fn script(&self, type_: &str, response: Response) {
+        self.state.lock().unwrap().responses.insert(type_.to_owned(), response);
+    }
+
+    /// Send an unsolicited message wrapper to every connected client
+    pub fn push_event(&self, event: Value) {
+        let mut state = self.state.lock().unwrap();
+        if state.clients.is_empty() {
+            state.queued.push(event);
+            return;
+        }
+
+        let line = format!("{}\n", event);
+        state.clients.retain(|mut client| client.write_all(line.as_bytes()).is_ok());
+    }
+
+    /// Deliver an `IncomingMessage` for `account`
+    pub fn push_incoming_message(&self, account: &str, message: IncomingMessageV1) {
+        self.push_event(json!({
+            "type": "IncomingMessage",
+            "version": "v1",
+            "data": message,
+            "account": account
+        }));
+    }
+
+    /// Every request received so far, in order
+    pub fn requests(&self) -> Vec<Value> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// The requests of type `type_` received so far, in order
+    pub fn requests_of(&self, type_: &str) -> Vec<Value> {
+        self.requests()
+            .into_iter()
+            .filter(|request| request["type"] == type_)
+            .collect()
+    }
+
+    /// Hang up on every connected client, as if signald restarted
+    pub fn disconnect(&self) {
+        for client in self.state.lock().unwrap().clients.drain(..) {
+            let _ = client.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+impl Drop for MockSignald {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        self.disconnect();
+        // Wake the accept loop so it notices it was stopped
+        let _ = UnixStream::connect(&self.path);
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+fn accept(listener: UnixListener, state: Arc<Mutex<State>>, stopped: Arc<AtomicBool>) {
+    for stream in listener.incoming() {
+        if stopped.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(_) => continue,
+        };
+        let reader = match stream.try_clone() {
+            Ok(reader) => reader,
+            Err(_) => continue,
+        };
+
+        {
+            let mut state = state.lock().unwrap();
+            let mut writer = &stream;
+            for event in state.queued.drain(..).collect::<Vec<_>>() {
+                let _ = writer.write_all(format!("{}\n", event).as_bytes());
+            }
+            state.clients.push(stream);
+        }
+
+        let state = state.clone();
+        thread::spawn(move || serve(reader, state));
+    }
+}
+
+fn serve(stream: UnixStream, state: Arc<Mutex<State>>) {
+    let mut writer = match stream.try_clone() {
+        Ok(writer) => writer,
+        Err(_) => return,
+    };
+
+    for line in BufReader::new(stream).lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        let request: Value = match serde_json::from_str(&line) {
+            Ok(request) => request,
+            Err(_) => continue,
+        };
+
+        // Record the request before answering so callers can assert on it right away
+        let response = {
+            let mut state = state.lock().unwrap();
+            state.requests.push(request.clone());
+            let type_ = request["type"].as_str().unwrap_or_default();
+            state.responses.get(type_).cloned()
+        };
+
+        let response = match response {
+            Some(Response::Ignore) => continue,
+            Some(Response::Data(data)) => json!({"id": request["id"], "type": request["type"], "data": data}),
+            Some(Response::Error { error_type, error }) => json!({
+                "id": request["id"],
+                "type": request["type"],
+                "error": error,
+                "error_type": error_type
+            }),
+            None => json!({"id": request["id"], "type": request["type"], "data": {}}),
+        };
+
+        let _guard = state.lock().unwrap();
+        if writer.write_all(format!("{}\n", response).as_bytes()).is_err() {
+            break;
+        }
+    }
+}
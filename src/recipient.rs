@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use uuid::Uuid;
+
+use crate::actions::{ResolveAddressError, SocketWrapper};
+use crate::errors::IdError;
+use crate::ids::{AccountId, PhoneNumber, ServiceAddress};
+use crate::socket::{AsyncSocket, Timeout};
+#[allow(deprecated)]
+use crate::types::JsonAddressV0;
+use crate::types::{JsonAddressV1, ResolveAddressRequestV1};
+
+/// Canonical identity of a Signal user, suitable as a map key.
+///
+/// The uuid identifies a user whenever it is known, so an address with both a uuid
+/// and a number is the same recipient as one with only the uuid. Use an [`AddressBook`]
+/// to match addresses that only carry a number with ones that carry the uuid.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Recipient {
+    Uuid(Uuid),
+    Number(PhoneNumber),
+}
+
+impl Recipient {
+    pub fn uuid(&self) -> Option<Uuid> {
+        match self {
+            Recipient::Uuid(uuid) => Some(*uuid),
+            Recipient::Number(_) => None,
+        }
+    }
+}
+
+impl From<Uuid> for Recipient {
+    fn from(uuid: Uuid) -> Self {
+        Recipient::Uuid(uuid)
+    }
+}
+
+impl From<PhoneNumber> for Recipient {
+    fn from(number: PhoneNumber) -> Self {
+        Recipient::Number(number)
+    }
+}
+
+impl From<ServiceAddress> for Recipient {
+    fn from(address: ServiceAddress) -> Self {
+        match (address.uuid(), address.number()) {
+            (Some(uuid), _) => Recipient::Uuid(uuid),
+            (None, Some(number)) => Recipient::Number(number.clone()),
+            (None, None) => unreachable!("ServiceAddress always has a uuid or a number"),
+        }
+    }
+}
+
+impl TryFrom<&JsonAddressV1> for Recipient {
+    type Error = IdError;
+
+    fn try_from(address: &JsonAddressV1) -> Result<Self, Self::Error> {
+        ServiceAddress::try_from(address.clone()).map(Recipient::from)
+    }
+}
+
+impl TryFrom<JsonAddressV1> for Recipient {
+    type Error = IdError;
+
+    fn try_from(address: JsonAddressV1) -> Result<Self, Self::Error> {
+        Recipient::try_from(&address)
+    }
+}
+
+#[allow(deprecated)]
+impl TryFrom<JsonAddressV0> for Recipient {
+    type Error = IdError;
+
+    // Other protocol releases may add fields to addresses
+    #[allow(clippy::needless_update)]
+    fn try_from(address: JsonAddressV0) -> Result<Self, Self::Error> {
+        Recipient::try_from(JsonAddressV1 {
+            number: address.number,
+            uuid: address.uuid,
+            relay: address.relay,
+            ..Default::default()
+        })
+    }
+}
+
+impl From<Recipient> for JsonAddressV1 {
+    fn from(recipient: Recipient) -> Self {
+        match recipient {
+            Recipient::Uuid(uuid) => JsonAddressV1 {
+                uuid: Some(uuid),
+                ..Default::default()
+            },
+            Recipient::Number(number) => JsonAddressV1 {
+                number: Some(number.as_str().to_owned()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<Recipient> for JsonAddressV0 {
+    #[allow(clippy::needless_update)]
+    fn from(recipient: Recipient) -> Self {
+        let address = JsonAddressV1::from(recipient);
+        JsonAddressV0 {
+            number: address.number,
+            uuid: address.uuid,
+            relay: address.relay,
+            ..Default::default()
+        }
+    }
+}
+
+/// Remembers which numbers and uuids belong together, merging partial addresses as
+/// they are seen in messages or come back from `resolve_address`
+#[derive(Clone, Debug, Default)]
+pub struct AddressBook {
+    uuids: HashMap<PhoneNumber, Uuid>,
+    numbers: HashMap<Uuid, PhoneNumber>,
+}
+
+impl AddressBook {
+    pub fn new() -> Self {
+        AddressBook::default()
+    }
+
+    /// Learn from an address, returning its canonical recipient
+    pub fn insert(&mut self, address: &JsonAddressV1) -> Result<Recipient, IdError> {
+        let address = ServiceAddress::try_from(address.clone())?;
+        if let (Some(uuid), Some(number)) = (address.uuid(), address.number()) {
+            self.uuids.insert(number.clone(), uuid);
+            self.numbers.insert(uuid, number.clone());
+        }
+
+        Ok(self.canonical(address.into()))
+    }
+
+    /// The canonical recipient for an address, using the uuid if the book knows it
+    pub fn recipient(&self, address: &JsonAddressV1) -> Result<Recipient, IdError> {
+        Recipient::try_from(address).map(|recipient| self.canonical(recipient))
+    }
+
+    fn canonical(&self, recipient: Recipient) -> Recipient {
+        match recipient {
+            Recipient::Number(number) => match self.uuids.get(&number) {
+                Some(uuid) => Recipient::Uuid(*uuid),
+                None => Recipient::Number(number),
+            },
+            recipient => recipient,
+        }
+    }
+
+    /// Whether two addresses belong to the same user as far as the book knows
+    pub fn same(&self, a: &JsonAddressV1, b: &JsonAddressV1) -> bool {
+        match (self.recipient(a), self.recipient(b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// The most complete address known for a recipient
+    pub fn address(&self, recipient: &Recipient) -> JsonAddressV1 {
+        match self.canonical(recipient.clone()) {
+            Recipient::Uuid(uuid) => JsonAddressV1 {
+                uuid: Some(uuid),
+                number: self.numbers.get(&uuid).map(|number| number.as_str().to_owned()),
+                ..Default::default()
+            },
+            recipient => recipient.into(),
+        }
+    }
+
+    /// The canonical recipient, asking signald for the uuid when the book doesn't know it yet
+    pub async fn resolve<T: AsyncSocket>(
+        &mut self,
+        signald: &SocketWrapper<T>,
+        account: AccountId,
+        recipient: &Recipient,
+    ) -> Result<Recipient, ResolveAddressError> {
+        let recipient = self.canonical(recipient.clone());
+        if let Recipient::Uuid(_) = recipient {
+            return Ok(recipient);
+        }
+
+        let request = ResolveAddressRequestV1::builder(account, recipient.clone()).build();
+        let resolved = signald.resolve_address(request, None, Timeout::Default).await?;
+
+        // signald answers with the partial address when it can't find the user
+        Ok(self.insert(&resolved).unwrap_or(recipient))
+    }
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use std::future::Future;
+use std::io::Result;
+use std::path::Path;
+use std::time::Duration;
+
+/// The few things the client needs from an async runtime. Everything else in
+/// the client is runtime independent.
+#[async_trait]
+pub trait Runtime: Send + Sync + 'static {
+    type Reader: Send + 'static;
+    type Writer: Send + 'static;
+
+    async fn connect(path: &Path) -> Result<(Self::Reader, Self::Writer)>;
+    async fn read_line(reader: &mut Self::Reader, buf: &mut String) -> Result<usize>;
+    async fn write_all(writer: &mut Self::Writer, buf: &[u8]) -> Result<()>;
+    async fn shutdown(writer: &mut Self::Writer) -> Result<()>;
+    async fn sleep(duration: Duration);
+    fn spawn<F>(future: F)
+    where
+        F: Future<Output = ()> + Send + 'static;
+}
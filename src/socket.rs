@@ -1,13 +1,188 @@
 use serde::Serialize;
 use serde_json::Value;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use uuid::Uuid;
 use async_trait::async_trait;
+use crate::errors::SignaldError;
 use crate::SocketError;
 
+/// Timeout applied to requests when neither the socket nor the call overrides it
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How long a single request waits for its response
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Timeout {
+    /// The connection's timeout, see `Socket::set_timeout`. Actions that block until the
+    /// user does something, `wait_for_scan` and `finish_link`, wait indefinitely instead.
+    #[default]
+    Default,
+    /// Wait for as long as the connection is up
+    Never,
+    After(Duration),
+}
+
+impl From<Duration> for Timeout {
+    fn from(duration: Duration) -> Self {
+        Timeout::After(duration)
+    }
+}
+
 #[async_trait]
 pub trait AsyncSocket {
-    async fn write<'a>(&'a mut self, buf: &'a [u8], id: &Uuid) -> Result<(), SocketError>;
-    async fn get_response<'a>(&'a mut self, id: Uuid) -> Result<Value, SocketError>;
+    /// Write a request and wait for the response with the same id.
+    ///
+    /// The pending response is forgotten when this returns or its future is dropped.
+    async fn request<'a>(&'a self, buf: &'a [u8], id: Uuid, timeout: Timeout) -> Result<Value, SocketError>;
+}
+
+/// How a supervised connection recovers when signald goes away
+#[derive(Clone, Debug)]
+pub struct Reconnect {
+    /// Delay before the first reconnect attempt, doubled after every failure
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between attempts
+    pub max_backoff: Duration,
+    /// Give up after this many failed attempts, `None` retries forever
+    pub max_attempts: Option<u32>,
+    /// Resend requests that were in flight when the connection dropped instead of failing them
+    pub replay_pending: bool,
+}
+
+impl Default for Reconnect {
+    fn default() -> Self {
+        Reconnect {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_attempts: None,
+            replay_pending: false,
+        }
+    }
+}
+
+impl Reconnect {
+    /// Delay before the given (1-based) reconnect attempt
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+/// State of the connection to signald, reported through `ClientEvent::Connection`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// The socket to signald was lost
+    Disconnected,
+    /// About to try connecting again
+    Reconnecting { attempt: u32 },
+    /// The socket was reestablished and subscriptions restored
+    Connected,
+    /// The connection is gone for good, every request will fail
+    Closed,
+}
+
+/// A call to an action signald has deprecated
+#[derive(Clone, Debug)]
+pub struct Deprecation {
+    pub action: &'static str,
+    /// Date (YYYY-MM-DD) after which signald may remove the action, as in its `#[deprecated]` note
+    pub removal_date: Option<&'static str>,
+}
+
+type DeprecationHook = Arc<dyn Fn(&Deprecation) + Send + Sync>;
+
+static DEPRECATION_HOOK: Mutex<Option<DeprecationHook>> = Mutex::new(None);
+
+/// Call `hook` whenever a deprecated action is called. Without a hook nothing is reported.
+pub fn set_deprecation_hook<F>(hook: F)
+where
+    F: Fn(&Deprecation) + Send + Sync + 'static,
+{
+    *DEPRECATION_HOOK.lock().unwrap() = Some(Arc::new(hook));
+}
+
+/// Called by every deprecated action before it sends its request. The bundled protocol
+/// document deprecates none, so only other documents use it.
+#[allow(dead_code)]
+pub(crate) fn report_deprecated(action: &'static str, removal_date: Option<&'static str>) {
+    // Not called under the lock, so the hook may set another hook or call deprecated actions
+    let hook = DEPRECATION_HOOK.lock().unwrap().clone();
+    if let Some(hook) = hook {
+        hook(&Deprecation { action, removal_date });
+    }
+}
+
+/// Send a request and return the `data` of its response as signald sent it
+pub(crate) async fn request_data<T: AsyncSocket, M: Serialize>(
+    socket: &T,
+    type_: &str,
+    msg: M,
+    id: Option<Uuid>,
+    timeout: Timeout,
+) -> Result<Value, SocketError> {
+    let id = id.unwrap_or_else(Uuid::new_v4);
+    let msg = MessageCommon::new(id.to_simple().to_string(), type_.to_owned(), "v1".to_owned(), msg);
+
+    let mut msg = serde_json::to_vec(&msg)?;
+    msg.push(b'\n');
+
+    let mut response = socket.request(&msg, id, timeout).await?;
+
+    match response.get("error") {
+        None => Ok(response["data"].take()),
+        Some(_) => Err(SocketError::Signald(serde_json::from_value::<SignaldError>(response)?.into())),
+    }
+}
+
+/// Every protocol type with its fields, and the protocol type of the fields holding one
+pub(crate) type FieldTable = [(&'static str, &'static [(&'static str, Option<&'static str>)])];
+
+/// Reject the keys of a JSON payload that aren't fields of its type, at any depth, which
+/// deserializing the payload would silently drop
+pub(crate) fn check_fields(payload: &Value, type_: &str, table: &FieldTable) -> Result<(), serde_json::Error> {
+    let (fields, payload) = match (table.iter().find(|(name, _)| *name == type_), payload.as_object()) {
+        (Some((_, fields)), Some(payload)) => (fields, payload),
+        _ => return Ok(()),
+    };
+
+    for (key, value) in payload {
+        match fields.iter().find(|(field, _)| field == key) {
+            None => {
+                let message = format!("unknown field `{}` in {}", key, type_);
+                return Err(serde::de::Error::custom(message));
+            }
+            Some((_, Some(nested))) => match value {
+                Value::Array(values) => {
+                    for value in values {
+                        check_fields(value, nested, table)?;
+                    }
+                }
+                value => check_fields(value, nested, table)?,
+            },
+            Some((_, None)) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Returns the account a successful subscribe (`true`) or unsubscribe (`false`) request changed
+pub(crate) fn subscription_change(request: &[u8], response: &Value) -> Option<(bool, String)> {
+    if response.get("error").is_some() {
+        return None;
+    }
+
+    let subscribed = match response.get("type").and_then(Value::as_str) {
+        Some("subscribe") => true,
+        Some("unsubscribe") => false,
+        _ => return None,
+    };
+
+    let request: Value = serde_json::from_slice(request).ok()?;
+    let account = request.get("account")?.as_str()?.to_owned();
+
+    Some((subscribed, account))
 }
 
 #[derive(Serialize, Default)]
@@ -22,11 +197,7 @@ pub struct MessageCommon<T> {
 }
 
 fn is_empty<T>(_data: &T) -> bool {
-    if std::mem::size_of::<T>() > 0 {
-        false
-    } else {
-        true
-    }
+    std::mem::size_of::<T>() == 0
 }
 
 #[derive(Serialize, Default)]
@@ -0,0 +1,107 @@
+//! Builds the crate against protocol documents other than the bundled one, the way
+//! `SIGNALD_PROTOCOL` is meant to be used
+
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::OnceLock;
+
+/// Run cargo on this crate with `SIGNALD_PROTOCOL` set, in a target directory of its
+/// own so the bindings of the main build are left alone
+fn cargo(protocol: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env("SIGNALD_PROTOCOL", protocol)
+        .env("CARGO_TARGET_DIR", Path::new(env!("CARGO_TARGET_TMPDIR")).join("protocol-override"))
+        .output()
+        .expect("Failed to run cargo")
+}
+
+fn write(name: &str, protocol: &Value) -> PathBuf {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::write(&path, protocol.to_string()).unwrap();
+    path
+}
+
+fn bundled() -> Value {
+    serde_json::from_str(include_str!("../protocol.json")).unwrap()
+}
+
+/// The bundled document, changed the ways other signald releases differ from it
+fn other_release() -> Value {
+    let mut protocol = bundled();
+    protocol["version"]["version"] = "0.19.1".into();
+
+    let types = protocol["types"]["v1"].as_object_mut().unwrap();
+    // Before stories and some of the client events
+    types["IncomingMessage"]["fields"].as_object_mut().unwrap().remove("story_message");
+    types.remove("StorageChange");
+    types.remove("WebSocketConnectionState");
+    // Addresses gaining a field, and an action this crate doesn't know yet
+    types["JsonAddress"]["fields"]["pni"] = json!({"type": "String"});
+    types.insert(
+        "GetStoriesRequest".to_owned(),
+        json!({"fields": {"account": {"type": "String", "required": true}}}),
+    );
+
+    let actions = protocol["actions"]["v1"].as_object_mut().unwrap();
+    actions.insert("get_stories".to_owned(), json!({"request": "GetStoriesRequest"}));
+    // Deprecated actions, with and without an account and a removal date
+    actions["get_servers"]["deprecated"] = true.into();
+    actions["get_servers"]["removal_date"] = 1641027661.into();
+    actions["get_remote_config"]["deprecated"] = true.into();
+
+    protocol
+}
+
+/// `other_release` written once, as the tests using it run at the same time
+fn other_release_path() -> &'static Path {
+    static PATH: OnceLock<PathBuf> = OnceLock::new();
+    PATH.get_or_init(|| write("other-release.json", &other_release()))
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn builds_against_other_release() {
+    let output = cargo(other_release_path(), &["check", "--lib", "--all-features"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+}
+
+#[test]
+fn reports_deprecated_actions() {
+    let test = "tests::tokio_deprecated_action_is_reported";
+    let output = cargo(other_release_path(), &["test", "--lib", "--features", "tokio", "--", "--exact", test]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("1 passed"));
+}
+
+#[test]
+fn relative_path_is_rejected() {
+    let output = cargo(Path::new("protocol.json"), &["check", "--lib"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("SIGNALD_PROTOCOL must be an absolute path"), "{}", stderr(&output));
+}
+
+#[test]
+fn missing_requirements_are_reported() {
+    let mut protocol = bundled();
+    protocol["types"]["v1"].as_object_mut().unwrap().remove("ResolveAddressRequest");
+    protocol["types"]["v1"]["IncomingMessage"]["fields"]
+        .as_object_mut()
+        .unwrap()
+        .remove("source");
+    protocol["actions"]["v1"].as_object_mut().unwrap().remove("version");
+    let path = write("missing-requirements.json", &protocol);
+
+    let output = cargo(&path, &["check", "--lib"]);
+    assert!(!output.status.success());
+    let stderr = stderr(&output);
+    assert!(stderr.contains("type ResolveAddressRequest (v1)"), "{}", stderr);
+    assert!(stderr.contains("field IncomingMessage.source (v1)"), "{}", stderr);
+    assert!(stderr.contains("action version taking VersionRequest (v1)"), "{}", stderr);
+}